@@ -2,6 +2,10 @@
 
 from dnb.core.types import DataChunk, Event, EventType, PipelineConfig, WaveletResult
 from dnb.engine.pipeline import Pipeline
+from dnb.errors import (
+    ConfigError, DnbError, DnbIOError, DnbRuntimeError, ValidationError,
+)
+from dnb.sources.array import ArraySource
 from dnb.sources.file import FileSource
 
 try:
@@ -11,6 +15,7 @@ except Exception:
     __version__ = "0.0.0-dev"
 
 __all__ = [
-    "DataChunk", "Event", "EventType", "FileSource",
-    "Pipeline", "PipelineConfig", "WaveletResult",
+    "ArraySource", "ConfigError", "DataChunk", "DnbError", "DnbIOError", "DnbRuntimeError",
+    "Event", "EventType", "FileSource", "Pipeline", "PipelineConfig",
+    "ValidationError", "WaveletResult",
 ]
\ No newline at end of file
@@ -1,4 +1,4 @@
-"""YAML configuration loader for DNB pipelines.
+"""YAML / JSON configuration loader for DNB pipelines.
 
 Builds a complete pipeline from a config file without writing Python.
 
@@ -6,52 +6,355 @@ Usage:
     from dnb.config import build_pipeline
     pipeline = build_pipeline("config.yaml")
     events = pipeline.run_offline()
+
+Or programmatically, with the same section/key names:
+    from dnb.config import PipelineBuilder
+    pipeline = PipelineBuilder().source_file("rec.npz").target_wave(amp_min=60.0).build()
 """
 
 from __future__ import annotations
 
+import copy
+import json
 import logging
-from math import pi
+from dataclasses import dataclass
+from math import pi, radians
 from pathlib import Path
 from typing import Any
 
 import numpy as np
 import yaml
 
-from dnb.core.types import PipelineConfig
+from dnb.core.types import EventType, PipelineConfig
+from dnb.errors import ConfigError, DnbIOError
 
 logger = logging.getLogger(__name__)
 
 
 def load_config(path: str | Path) -> dict[str, Any]:
-    """Load a YAML config file (UTF-8 encoded)."""
+    """Load a config file (UTF-8 encoded): JSON for .json, YAML otherwise.
+
+    Both formats produce the same dict. Raises DnbIOError if the file
+    can't be read, ConfigError if it doesn't parse or isn't a mapping.
+    """
     path = Path(path)
     if not path.exists():
-        raise FileNotFoundError(f"Config not found: {path}")
-    with open(path, "r", encoding="utf-8") as f:
-        cfg = yaml.safe_load(f)
+        raise DnbIOError(f"Config not found: {path}")
+    try:
+        text = path.read_text(encoding="utf-8")
+    except OSError as e:
+        raise DnbIOError(f"Could not read config {path}: {e}") from e
+    if path.suffix.lower() == ".json":
+        return from_json_str(text, source=str(path))
+    return from_yaml_str(text, source=str(path))
+
+
+def from_yaml_str(text: str, source: str = "<string>") -> dict[str, Any]:
+    """Parse an in-memory YAML config."""
+    try:
+        cfg = yaml.safe_load(text)
+    except yaml.YAMLError as e:
+        raise ConfigError(f"Invalid YAML in {source}: {e}") from e
+    return _check_mapping(cfg, source)
+
+
+def from_json_str(text: str, source: str = "<string>") -> dict[str, Any]:
+    """Parse an in-memory JSON config."""
+    try:
+        cfg = json.loads(text)
+    except json.JSONDecodeError as e:
+        raise ConfigError(f"Invalid JSON in {source}: {e}") from e
+    return _check_mapping(cfg, source)
+
+
+def _check_mapping(cfg: Any, source: str) -> dict[str, Any]:
     if not isinstance(cfg, dict):
-        raise ValueError(f"Config must be a YAML mapping, got {type(cfg).__name__}")
+        raise ConfigError(f"Config {source} must be a mapping, got {type(cfg).__name__}")
     return cfg
 
 
+@dataclass(frozen=True)
+class ConfigDiff:
+    """One difference between two config dicts.
+
+    path: dotted key, e.g. 'target_wave.amp_min', with list entries by
+    index, e.g. 'filters[1].q'. A whole section that exists in only one
+    config is reported once, not key by key.
+    """
+    kind: str  # "added" | "removed" | "changed"
+    path: str
+    old: Any = None
+    new: Any = None
+
+    def __str__(self) -> str:
+        if self.kind == "added":
+            return f"+ {self.path}: {self.new!r}"
+        if self.kind == "removed":
+            return f"- {self.path}: {self.old!r}"
+        return f"~ {self.path}: {self.old!r} -> {self.new!r}"
+
+
+def diff_configs(a: dict[str, Any], b: dict[str, Any], _prefix: str = "") -> list[ConfigDiff]:
+    """Compare two loaded configs. Returns differences going from a to b."""
+    diffs: list[ConfigDiff] = []
+    for key in list(a) + [k for k in b if k not in a]:
+        path = f"{_prefix}{key}"
+        if key not in b:
+            diffs.append(ConfigDiff("removed", path, old=a[key]))
+        elif key not in a:
+            diffs.append(ConfigDiff("added", path, new=b[key]))
+        else:
+            diffs.extend(_diff_values(a[key], b[key], path))
+    return diffs
+
+
+def _diff_values(old: Any, new: Any, path: str) -> list[ConfigDiff]:
+    if isinstance(old, dict) and isinstance(new, dict):
+        return diff_configs(old, new, f"{path}.")
+    if isinstance(old, list) and isinstance(new, list):
+        diffs: list[ConfigDiff] = []
+        for i in range(max(len(old), len(new))):
+            item = f"{path}[{i}]"
+            if i >= len(new):
+                diffs.append(ConfigDiff("removed", item, old=old[i]))
+            elif i >= len(old):
+                diffs.append(ConfigDiff("added", item, new=new[i]))
+            else:
+                diffs.extend(_diff_values(old[i], new[i], item))
+        return diffs
+    if old != new:
+        return [ConfigDiff("changed", path, old=old, new=new)]
+    return []
+
+
 def build_pipeline_config(cfg: dict[str, Any]) -> PipelineConfig:
     """Build PipelineConfig from the 'pipeline' section."""
     p = cfg.get("pipeline", {})
+    epochs = p.get("active_epochs")
     return PipelineConfig(
         sample_rate=float(p.get("sample_rate", 30_000.0)),
-        n_channels=int(p.get("n_channels", 1)),
+        channel_id=int(p.get("channel_id", 0)),
         buffer_duration=float(p.get("buffer_duration", 10.0)),
         chunk_duration=float(p.get("chunk_duration", 0.5)),
+        active_epochs=(
+            [(float(a), float(b)) for a, b in epochs] if epochs is not None else None
+        ),
+        nan_policy=str(p.get("nan_policy", "hold")),
     )
 
 
+# Detector sections and their default ids, for reference checks
+_DETECTOR_SECTIONS = {
+    "target_wave": "slow_wave",
+    "amplitude_monitor": "ied_monitor",
+    "envelope_detector": "envelope",
+    "ripple_detector": "ripple",
+    "spindle_detector": "spindle",
+    "cross_correlation": "xcorr",
+}
+
+
+def _analysis_rate(cfg: dict[str, Any]) -> float:
+    """Rate the filters and detectors run at: after the Downsampler, if enabled.
+
+    From the configured pipeline.sample_rate; a file source may turn out
+    to have a different one, but the Downsampler output stays close.
+    """
+    try:
+        rate = float(cfg.get("pipeline", {}).get("sample_rate", 30_000.0))
+        ds = cfg.get("downsampler")
+        if isinstance(ds, dict) and ds.get("enabled", True):
+            rate /= max(1, round(rate / float(ds.get("target_rate", 500.0))))
+    except (TypeError, ValueError, ZeroDivisionError):
+        return float("inf")  # reported by the positivity checks
+    return rate if rate > 0 else float("inf")
+
+
+def validate_config(cfg: dict[str, Any]) -> list[str]:
+    """Check a config dict before anything is built.
+
+    Collects every problem instead of stopping at the first: positive
+    rates/durations, 0 < lo < hi < Nyquist (at the analysis rate) for
+    every band and filter frequency, known source and filter
+    types, and trigger detector ids that name a configured detector
+    (disabled ones count — a trigger may reference a switched-off
+    detector).
+
+    Returns:
+        Problem descriptions; empty when the config is valid.
+    """
+    problems: list[str] = []
+    nyquist = _analysis_rate(cfg) / 2.0
+
+    def check_band(where: str, band: Any) -> None:
+        try:
+            lo, hi = (float(v) for v in band)
+        except (TypeError, ValueError):
+            problems.append(f"{where}: expected [lo, hi], got {band!r}")
+            return
+        if not 0.0 < lo < hi:
+            problems.append(f"{where}: need 0 < lo < hi, got [{lo:g}, {hi:g}]")
+        elif hi >= nyquist:
+            problems.append(
+                f"{where}: {hi:g} Hz is at or above Nyquist "
+                f"({nyquist:g} Hz at the {2 * nyquist:g} Hz analysis rate)"
+            )
+
+    def check_positive(where: str, value: Any) -> None:
+        try:
+            ok = float(value) > 0
+        except (TypeError, ValueError):
+            ok = False
+        if not ok:
+            problems.append(f"{where}: must be > 0, got {value!r}")
+
+    p = cfg.get("pipeline", {})
+    for key in ("sample_rate", "buffer_duration", "chunk_duration"):
+        if key in p:
+            check_positive(f"pipeline.{key}", p[key])
+    if p.get("nan_policy", "hold") not in ("hold", "zero", "skip"):
+        problems.append(
+            f"pipeline.nan_policy: must be hold, zero or skip, got {p['nan_policy']!r}"
+        )
+
+    src = cfg.get("source", {})
+    kind = str(src.get("type", "file")).lower()
+    if kind not in ("file", "nplay", "cerebus", "auto"):
+        problems.append(f"source.type: unknown type {kind!r}")
+    elif kind == "file" and not src.get("path"):
+        problems.append("source.path: required for file source")
+
+    if "downsampler" in cfg:
+        check_positive("downsampler.target_rate", cfg["downsampler"].get("target_rate", 500.0))
+
+    w = cfg.get("wavelet", {})
+    check_band("wavelet.freq_min/freq_max",
+               (w.get("freq_min", 0.5), w.get("freq_max", 30.0)))
+
+    for section in ("target_wave", "amplitude_monitor", "envelope_detector",
+                    "ripple_detector", "spindle_detector"):
+        if "freq_range" in cfg.get(section, {}):
+            check_band(f"{section}.freq_range", cfg[section]["freq_range"])
+    tw = cfg.get("target_wave", {})
+    if "target_phase" in tw and tw.get("target_phase_deg") is None:
+        try:
+            _parse_phase(tw["target_phase"])
+        except (ConfigError, ValueError):
+            problems.append(
+                f"target_wave.target_phase: expected radians (e.g. 0, 'pi', '3pi/2'), got "
+                f"{tw['target_phase']!r} — degrees go in target_phase_deg (90 = up-state peak)"
+            )
+    if tw.get("amp_min_floor") is not None:
+        try:
+            floor, amp_min = float(tw["amp_min_floor"]), float(tw.get("amp_min", 75.0))
+        except (TypeError, ValueError):
+            problems.append(
+                f"target_wave.amp_min_floor: expected a number, got {tw['amp_min_floor']!r}"
+            )
+        else:
+            if not 0.0 < floor <= amp_min:
+                problems.append(
+                    f"target_wave.amp_min_floor: must be in (0, {amp_min:g}] (amp_min), "
+                    f"got {floor:g}"
+                )
+    for key in ("band_a", "band_b"):
+        if key in cfg.get("cross_correlation", {}):
+            check_band(f"cross_correlation.{key}", cfg["cross_correlation"][key])
+
+    for i, f in enumerate(cfg.get("filters") or []):
+        if f.get("type") not in (
+            "comb_notch", "notch", "iir", "moving_average", "baseline_removal",
+        ):
+            problems.append(f"filters[{i}].type: unknown filter type {f.get('type')!r}")
+        if f.get("type") == "iir":
+            for key in ("b", "a", "design_rate"):
+                if key not in f:
+                    problems.append(f"filters[{i}].{key}: required for an iir filter")
+            continue
+        if f.get("type") == "moving_average":
+            check_positive(f"filters[{i}].window_ms", f.get("window_ms", 20.0))
+            continue
+        # Comb harmonics above Nyquist are skipped by design; the base must fit
+        key, default = {
+            "comb_notch": ("base_freq", 50.0),
+            "baseline_removal": ("cutoff_hz", 0.1),
+        }.get(f.get("type"), ("freq", 50.0))
+        try:
+            freq = float(f.get(key, default))
+        except (TypeError, ValueError):
+            problems.append(f"filters[{i}].{key}: expected a number, got {f.get(key)!r}")
+            continue
+        if freq >= nyquist:
+            problems.append(
+                f"filters[{i}].{key}: {freq:g} Hz is at or above Nyquist ({nyquist:g} Hz)"
+            )
+
+    a = cfg.get("audio", {})
+    for name in a.get("trigger_on", ["STIM"]):
+        if str(name).upper() not in EventType.__members__:
+            problems.append(
+                f"audio.trigger_on: unknown event type {name!r} "
+                f"(expected one of {', '.join(EventType.__members__)})"
+            )
+
+    detector_ids = {
+        cfg[s].get("id", default) if isinstance(cfg.get(s), dict) else default
+        for s, default in _DETECTOR_SECTIONS.items()
+        if s in cfg or s == "target_wave"
+    }
+    tr = cfg.get("trigger", {})
+    for key in ("activation_detector_id", "inhibition_detector_id"):
+        refs = tr.get(key, "slow_wave" if key == "activation_detector_id" else None)
+        if refs is None:
+            continue
+        if isinstance(refs, str):
+            refs = [refs]
+        if not refs:
+            problems.append(f"trigger.{key}: empty list")
+        for ref in refs:
+            if ref not in detector_ids:
+                problems.append(
+                    f"trigger.{key}: no detector with id {ref!r} "
+                    f"(configured: {', '.join(sorted(detector_ids))})"
+                )
+    timing_id = tr.get("timing_detector_id")
+    if timing_id is not None:
+        act_ids = tr.get("activation_detector_id", "slow_wave")
+        act_ids = [act_ids] if isinstance(act_ids, str) else list(act_ids or [])
+        if timing_id not in act_ids:
+            problems.append(
+                f"trigger.timing_detector_id: {timing_id!r} is not one of the "
+                f"activation detectors ({', '.join(map(str, act_ids))})"
+            )
+    if tr.get("activation_mode", "all") not in ("all", "any"):
+        problems.append(
+            f"trigger.activation_mode: must be 'all' or 'any', got {tr['activation_mode']!r}"
+        )
+
+    return problems
+
+
+def check_config(cfg: dict[str, Any]) -> None:
+    """Raise ConfigError listing every problem validate_config finds."""
+    problems = validate_config(cfg)
+    if problems:
+        raise ConfigError(
+            f"{len(problems)} config problem(s):\n" + "\n".join(f"  - {p}" for p in problems)
+        )
+
+
 def _parse_phase(value) -> float:
-    """Parse a phase value — supports 'pi', '3pi/2', '0', '3.14', etc."""
+    """Parse a phase value in radians — supports 'pi', '3pi/2', '0', '3.14', etc."""
     if isinstance(value, (int, float)):
         return float(value)
     if isinstance(value, str):
         s = value.strip().lower().replace(" ", "")
+        if s.endswith(("deg", "°")):
+            # Degrees are only accepted in the sine convention, see below
+            raise ConfigError(
+                f"target_phase {value!r}: target_phase is in radians (0 = peak); "
+                f"use target_phase_deg for degrees (sine convention, 90 = up-state peak)"
+            )
         if s == "pi":
             return pi
         if s in ("3pi/2", "3*pi/2", "1.5pi", "1.5*pi"):
@@ -64,23 +367,122 @@ def _parse_phase(value) -> float:
     return float(value)
 
 
+def phase_from_sine_deg(degrees: float) -> float:
+    """Sine-convention degrees → target_phase in radians.
+
+    Protocols quote phase on a sine: 0° rising zero crossing, 90° up-state
+    peak, 180° falling zero crossing, 270° trough. The wavelet phase used
+    here is a cosine's (0 = peak), i.e. 90° behind.
+    """
+    return radians(degrees - 90.0) % (2 * pi)
+
+
+def build_filters(specs: list[dict[str, Any]]) -> list:
+    """Build chunk filters from the `filters:` list.
+
+    Each entry has a `type` plus that filter's keyword arguments, e.g.
+    {type: comb_notch, base_freq: 60.0, n_harmonics: 3}.
+    """
+    from dnb.modules.filters import (
+        BaselineRemovalFilter, CombNotchFilter, IirFilter, MovingAverageFilter, NotchFilter,
+    )
+
+    builders = {
+        "comb_notch": lambda f: CombNotchFilter(
+            id=f.get("id", "line_noise"),
+            base_freq=float(f.get("base_freq", 50.0)),
+            n_harmonics=int(f.get("n_harmonics", 3)),
+            q=float(f.get("q", 30.0)),
+        ),
+        "notch": lambda f: NotchFilter(
+            id=f.get("id", "notch"),
+            freq=float(f.get("freq", 50.0)),
+            q=float(f.get("q", 30.0)),
+        ),
+        "iir": lambda f: IirFilter(
+            id=f.get("id", "iir"),
+            b=[float(v) for v in f["b"]],
+            a=[float(v) for v in f["a"]],
+            design_rate=float(f["design_rate"]),
+        ),
+        "moving_average": lambda f: MovingAverageFilter(
+            id=f.get("id", "moving_average"),
+            window_ms=float(f.get("window_ms", 20.0)),
+        ),
+        "baseline_removal": lambda f: BaselineRemovalFilter(
+            id=f.get("id", "baseline_removal"),
+            cutoff_hz=float(f.get("cutoff_hz", 0.1)),
+        ),
+    }
+    filters = []
+    for f in specs:
+        if not f.get("enabled", True):
+            continue
+        kind = f.get("type")
+        if kind not in builders:
+            raise ConfigError(f"Unknown filter type: {kind!r} (expected one of {sorted(builders)})")
+        filters.append(builders[kind](f))
+    return filters
+
+
 def build_modules(cfg: dict[str, Any]) -> list:
-    """Build the module chain from config sections."""
+    """Build the module chain from config sections.
+
+    Optional sections (and `filters:` entries) with `enabled: false` are
+    skipped; so are `target_wave` and `trigger`.
+    A trigger referencing a skipped detector just never sees it active.
+    """
     from dnb.modules.amplitude_monitor import AmplitudeMonitor
     from dnb.modules.audio_stim import AudioStimulator
+    from dnb.modules.auto_gain import AutoGainFilter
+    from dnb.modules.burst import RippleDetector, SpindleDetector
+    from dnb.modules.cross_corr import CrossCorrMonitor
     from dnb.modules.downsampler import Downsampler
+    from dnb.modules.envelope import EnvelopeDetector
     from dnb.modules.stim_trigger import StimTrigger
     from dnb.modules.twave_detector import TWaveDetector
     from dnb.modules.wavelet import WaveletConvolution
 
     modules = []
 
+    toggleable = (
+        "downsampler", "auto_gain", "target_wave", "amplitude_monitor",
+        "envelope_detector", "ripple_detector", "spindle_detector",
+        "cross_correlation", "trigger", "audio",
+    )
+    skipped = [
+        name for name in toggleable
+        if isinstance(cfg.get(name), dict) and cfg[name].get("enabled", True) is False
+    ]
+    skipped += [
+        f"filters[{i}] ({f.get('type')})" for i, f in enumerate(cfg.get("filters") or [])
+        if f.get("enabled", True) is False
+    ]
+    if skipped:
+        logger.info("Config: disabled, skipped: %s", ", ".join(skipped))
+
     # Downsampler (optional, for live hardware)
     if "downsampler" in cfg:
         d = cfg["downsampler"]
         if d.get("enabled", True):
             modules.append(Downsampler(target_rate=float(d.get("target_rate", 500.0))))
 
+    # Chunk filters (optional, run in listed order before the ring buffer)
+    modules.extend(build_filters(cfg.get("filters") or []))
+
+    # Automatic gain normalization (optional, runs before the ring buffer)
+    if "auto_gain" in cfg:
+        ag = cfg["auto_gain"]
+        if ag.get("enabled", True):
+            modules.append(AutoGainFilter(
+                id=ag.get("id", "auto_gain"),
+                target_rms=float(ag.get("target_rms", 50.0)),
+                rms_window_s=float(ag.get("rms_window_s", 10.0)),
+                gain_tau_s=float(ag.get("gain_tau_s", 30.0)),
+                min_gain=float(ag.get("min_gain", 0.01)),
+                max_gain=float(ag.get("max_gain", 100.0)),
+            ))
+
     # Wavelet convolution
     w = cfg.get("wavelet", {})
     modules.append(WaveletConvolution(
@@ -95,7 +497,11 @@ def build_modules(cfg: dict[str, Any]) -> list:
     detector_kwargs = {
         "id": tw.get("id", "slow_wave"),
         "freq_range": tuple(tw.get("freq_range", [0.5, 2.0])),
-        "target_phase": _parse_phase(tw.get("target_phase", 0.0)),
+        "target_phase": (
+            phase_from_sine_deg(float(tw["target_phase_deg"]))
+            if tw.get("target_phase_deg") is not None
+            else _parse_phase(tw.get("target_phase", 0.0))
+        ),
         "prediction_limit_s": float(tw.get("prediction_limit_s", 0.15)),
         "amp_min": float(tw.get("amp_min", 75.0)),
         "amp_max": float(tw.get("amp_max", 300.0)),
@@ -111,8 +517,29 @@ def build_modules(cfg: dict[str, Any]) -> list:
         detector_kwargs["template_threshold"] = tw["template_threshold"]  # None disables
     if "template_window_s" in tw:
         detector_kwargs["template_window_s"] = float(tw["template_window_s"])
+    if "template_metric" in tw:
+        detector_kwargs["template_metric"] = str(tw["template_metric"])
+    if "retain_waveform" in tw:
+        detector_kwargs["retain_waveform"] = bool(tw["retain_waveform"])
+    if tw.get("period_smoothing") is not None:
+        detector_kwargs["period_smoothing"] = float(tw["period_smoothing"])
+    if tw.get("target_rate_per_min") is not None:
+        detector_kwargs["target_rate_per_min"] = float(tw["target_rate_per_min"])
+        for key in ("rate_window_s", "adapt_gain", "amp_min_floor"):
+            if tw.get(key) is not None:
+                detector_kwargs[key] = float(tw[key])
+    for key in ("debug", "record_candidates", "track_prediction_error"):
+        if key in tw:
+            detector_kwargs[key] = bool(tw[key])
+    for key in ("trace_length", "candidate_log_length"):
+        if key in tw:
+            detector_kwargs[key] = int(tw[key])
+    for key in ("band_name", "role"):
+        if key in tw:
+            detector_kwargs[key] = str(tw[key])
 
-    modules.append(TWaveDetector(**detector_kwargs))
+    if tw.get("enabled", True):
+        modules.append(TWaveDetector(**detector_kwargs))
 
     # Amplitude monitor (IED inhibition, optional)
     if "amplitude_monitor" in cfg:
@@ -124,32 +551,112 @@ def build_modules(cfg: dict[str, Any]) -> list:
                 "warmup_chunks": int(am.get("warmup_chunks", 20)),
                 "filter_order": int(am.get("filter_order", 4)),
             }
+            for key in ("band_name", "role"):
+                if key in am:
+                    kwargs[key] = str(am[key])
+            if am.get("baseline_chunks") is not None:
+                kwargs["baseline_chunks"] = int(am["baseline_chunks"])
+            if am.get("freeze_statistics_after_s") is not None:
+                kwargs["freeze_statistics_after_s"] = float(am["freeze_statistics_after_s"])
             if "threshold" in am:
                 kwargs["threshold"] = float(am["threshold"])
             else:
                 kwargs["adaptive_n_std"] = float(am.get("adaptive_n_std", 3.0))
+            for key in ("release_n_std", "release_threshold"):
+                if am.get(key) is not None:
+                    kwargs[key] = float(am[key])
             modules.append(AmplitudeMonitor(**kwargs))
 
+    # Band envelope detector (optional)
+    if "envelope_detector" in cfg:
+        ed = cfg["envelope_detector"]
+        if ed.get("enabled", True):
+            kwargs = {
+                "id": ed.get("id", "envelope"),
+                "freq_range": tuple(ed.get("freq_range", [0.5, 4.0])),
+                "z_threshold": float(ed.get("z_threshold", 3.0)),
+                "warmup_chunks": int(ed.get("warmup_chunks", 20)),
+                "filter_order": int(ed.get("filter_order", 2)),
+            }
+            if ed.get("smoothing_hz") is not None:
+                kwargs["smoothing_hz"] = float(ed["smoothing_hz"])
+            for key in ("band_name", "role"):
+                if key in ed:
+                    kwargs[key] = str(ed[key])
+            modules.append(EnvelopeDetector(**kwargs))
+
+    # Burst detectors (optional). Ripples need analysis rate > 2 × band top.
+    for section, cls, defaults in (
+        ("ripple_detector", RippleDetector, ("ripple", [80.0, 250.0], 3.0, 20.0, 200.0)),
+        ("spindle_detector", SpindleDetector, ("spindle", [11.0, 16.0], 2.0, 500.0, 2000.0)),
+    ):
+        if section not in cfg:
+            continue
+        bd = cfg[section]
+        if not bd.get("enabled", True):
+            continue
+        det_id, freq_range, z_threshold, min_ms, max_ms = defaults
+        kwargs = {
+            "id": bd.get("id", det_id),
+            "freq_range": tuple(bd.get("freq_range", freq_range)),
+            "z_threshold": float(bd.get("z_threshold", z_threshold)),
+            "min_duration_ms": float(bd.get("min_duration_ms", min_ms)),
+            "max_duration_ms": float(bd.get("max_duration_ms", max_ms)),
+            "warmup_chunks": int(bd.get("warmup_chunks", 20)),
+        }
+        if bd.get("smoothing_hz") is not None:
+            kwargs["smoothing_hz"] = float(bd["smoothing_hz"])
+        for key in ("band_name", "role"):
+            if key in bd:
+                kwargs[key] = str(bd[key])
+        modules.append(cls(**kwargs))
+
+    # Cross-correlation lag between two bands (diagnostic, optional)
+    if "cross_correlation" in cfg:
+        xc = cfg["cross_correlation"]
+        if xc.get("enabled", True):
+            modules.append(CrossCorrMonitor(
+                id=xc.get("id", "xcorr"),
+                band_a=tuple(xc.get("band_a", [0.5, 2.0])),
+                band_b=tuple(xc.get("band_b", [0.5, 4.0])),
+                window_s=float(xc.get("window_s", 2.0)),
+                max_lag_s=float(xc.get("max_lag_s", 0.25)),
+                filter_order=int(xc.get("filter_order", 2)),
+            ))
+
     # Stim trigger (simplified — no phase delay calculation)
     tr = cfg.get("trigger", {})
     inh_id = tr.get("inhibition_detector_id")
     if inh_id is None and "amplitude_monitor" in cfg and cfg["amplitude_monitor"].get("enabled", True):
         inh_id = cfg.get("amplitude_monitor", {}).get("id", "ied_monitor")
 
-    modules.append(StimTrigger(
-        activation_detector_id=tr.get("activation_detector_id", "slow_wave"),
-        inhibition_detector_id=inh_id,
-        n_pulses=int(tr.get("n_pulses", 1)),
-        backoff_s=float(tr.get("backoff_s", 5.0)),
-        inhibition_cooldown_s=float(tr.get("inhibition_cooldown_s", 5.0)),
-    ))
+    if tr.get("enabled", True):
+        modules.append(StimTrigger(
+            id=str(tr.get("id", "trigger")),
+            activation_detector_id=tr.get("activation_detector_id", "slow_wave"),
+            inhibition_detector_id=inh_id,
+            n_pulses=int(tr.get("n_pulses", 1)),
+            backoff_s=float(tr.get("backoff_s", 5.0)),
+            inhibition_cooldown_s=float(tr.get("inhibition_cooldown_s", 5.0)),
+            inter_pulse_interval_s=(
+                float(tr["inter_pulse_interval_s"])
+                if tr.get("inter_pulse_interval_s") is not None else None
+            ),
+            stim_delay_s=float(tr.get("stim_delay_s", 0.0)),
+            activation_mode=tr.get("activation_mode", "all"),
+            min_activation_confidence=(
+                float(tr["min_activation_confidence"])
+                if tr.get("min_activation_confidence") is not None else None
+            ),
+            confidence_key=str(tr.get("confidence_key", "template_score")),
+            timing_detector_id=tr.get("timing_detector_id"),
+        ))
 
     # Audio (optional)
-    if "audio" in cfg:
+    if "audio" in cfg and cfg["audio"].get("enabled", True):
         a = cfg["audio"]
         wav_path = a.get("wav_path")
         if wav_path and Path(wav_path).exists():
-            from dnb.core.types import EventType
             trigger_names = a.get("trigger_on", ["STIM"])
             modules.append(AudioStimulator(
                 wav_path=wav_path,
@@ -168,7 +675,10 @@ def build_source(cfg: dict[str, Any]):
 
     if kind == "file":
         if not src.get("path"):
-            raise ValueError("source.path required for file source")
+            raise ConfigError("source.path required for file source")
+        if Path(src["path"]).suffix.lower() == ".edf":
+            from dnb.sources.edf import EdfSource
+            return EdfSource(src["path"], channel_label=src.get("channel_label"))
         return FileSource(src["path"])
     elif kind == "nplay":
         from dnb.sources.live import NPlaySource
@@ -179,16 +689,114 @@ def build_source(cfg: dict[str, Any]):
             inst_addr=src.get("inst_addr", ""),
             client_addr=src.get("client_addr", "0.0.0.0"),
         )
+    elif kind == "auto":
+        # NPlay first, then Cerebus — same order as run.py's live mode
+        for fallback in ("nplay", "cerebus"):
+            try:
+                return build_source({**cfg, "source": {**src, "type": fallback}})
+            except ImportError:
+                logger.info("Source %s not available", fallback)
+        raise ConfigError("source.type auto: no live source available (pycbsdk not installed?)")
     else:
-        raise ValueError(f"Unknown source type: {kind}")
+        raise ConfigError(f"Unknown source type: {kind}")
 
 
 def build_pipeline(config_path: str | Path):
     """Build a complete Pipeline from a YAML config file."""
     from dnb.engine.pipeline import Pipeline
     cfg = load_config(config_path)
+    check_config(cfg)
     return Pipeline(
         source=build_source(cfg),
         modules=build_modules(cfg),
         config=build_pipeline_config(cfg),
-    )
\ No newline at end of file
+    )
+
+
+class PipelineBuilder:
+    """Fluent builder for the config dict a YAML file would produce.
+
+    Each method sets keys of one YAML section (keyword names match the
+    YAML keys) and returns the builder, so
+
+        PipelineBuilder().source_file("rec.npz").target_wave(amp_min=60.0).build()
+
+    builds the same pipeline as a YAML file with those sections.
+    Repeated calls for a section merge into it.
+    """
+
+    def __init__(self, base: dict[str, Any] | None = None) -> None:
+        self._cfg: dict[str, Any] = copy.deepcopy(base) if base else {}
+
+    @classmethod
+    def from_file(cls, path: str | Path) -> PipelineBuilder:
+        """Start from an existing YAML/JSON config and override from there."""
+        return cls(load_config(path))
+
+    def _section(self, name: str, **kwargs: Any) -> PipelineBuilder:
+        self._cfg.setdefault(name, {}).update(kwargs)
+        return self
+
+    def pipeline(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("pipeline", **kwargs)
+
+    def source_file(self, path: str | Path) -> PipelineBuilder:
+        self._cfg["source"] = {"type": "file", "path": str(path)}
+        return self
+
+    def source(self, type: str, **kwargs: Any) -> PipelineBuilder:
+        self._cfg["source"] = {"type": type, **kwargs}
+        return self
+
+    def downsampler(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("downsampler", **kwargs)
+
+    def filter(self, type: str, **kwargs: Any) -> PipelineBuilder:
+        """Append one entry to the `filters:` list."""
+        self._cfg.setdefault("filters", []).append({"type": type, **kwargs})
+        return self
+
+    def auto_gain(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("auto_gain", **kwargs)
+
+    def wavelet(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("wavelet", **kwargs)
+
+    def target_wave(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("target_wave", **kwargs)
+
+    def amplitude_monitor(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("amplitude_monitor", **kwargs)
+
+    def envelope_detector(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("envelope_detector", **kwargs)
+
+    def ripple_detector(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("ripple_detector", **kwargs)
+
+    def spindle_detector(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("spindle_detector", **kwargs)
+
+    def cross_correlation(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("cross_correlation", **kwargs)
+
+    def trigger(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("trigger", **kwargs)
+
+    def audio(self, **kwargs: Any) -> PipelineBuilder:
+        return self._section("audio", **kwargs)
+
+    def to_dict(self) -> dict[str, Any]:
+        """The config dict, as load_config would return it."""
+        return copy.deepcopy(self._cfg)
+
+    def build(self):
+        """Build the Pipeline. Fails the same way a bad YAML file would."""
+        from dnb.engine.pipeline import Pipeline
+        cfg = self.to_dict()
+        check_config(cfg)
+        return Pipeline(
+            source=build_source(cfg),
+            modules=build_modules(cfg),
+            config=build_pipeline_config(cfg),
+        )
@@ -1,6 +1,8 @@
 from dnb.core.ring_buffer import RingBuffer
+from dnb.core.stats import RollingStats, WindowedStats
 from dnb.core.types import DataChunk, Event, EventType, PipelineConfig, WaveletResult
 
 __all__ = [
-    "DataChunk", "Event", "EventType", "PipelineConfig", "RingBuffer", "WaveletResult",
+    "DataChunk", "Event", "EventType", "PipelineConfig", "RingBuffer", "RollingStats",
+    "WaveletResult", "WindowedStats",
 ]
\ No newline at end of file
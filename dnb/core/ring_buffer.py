@@ -74,6 +74,26 @@ class RingBuffer:
                     [self._buf[start:], self._buf[:n_samples - first]]
                 )
 
+    def get_state(self) -> dict:
+        """Contents and write position, for Pipeline.save_state()."""
+        with self._lock:
+            return {
+                "buf": self._buf.copy(),
+                "write_pos": self._write_pos,
+                "total_written": self._total_written,
+            }
+
+    def set_state(self, state: dict) -> None:
+        with self._lock:
+            if state["buf"].shape[0] != self._capacity:
+                raise ValueError(
+                    f"Saved buffer holds {state['buf'].shape[0]} samples, "
+                    f"this one {self._capacity}"
+                )
+            self._buf[:] = state["buf"]
+            self._write_pos = state["write_pos"]
+            self._total_written = state["total_written"]
+
     def clear(self) -> None:
         with self._lock:
             self._buf[:] = 0
@@ -0,0 +1,97 @@
+"""Running statistics for adaptive (z-score) thresholds.
+
+RollingStats accumulates over the whole session (Welford; numerically
+stable over hours of data). WindowedStats covers only the most recent
+`window` values, so the baseline follows slow drift. Both expose the
+same interface.
+"""
+
+from __future__ import annotations
+
+from collections import deque
+
+import numpy as np
+from numpy.typing import NDArray
+
+
+class RollingStats:
+    def __init__(self) -> None:
+        self.count = 0
+        self.mean = 0.0
+        self._m2 = 0.0
+
+    def update(self, value: float) -> None:
+        self.count += 1
+        d = value - self.mean
+        self.mean += d / self.count
+        self._m2 += d * (value - self.mean)
+
+    def update_batch(self, values: NDArray[np.float64]) -> None:
+        """Merge a block of values at once (Chan et al. parallel update)."""
+        n_b = values.shape[0]
+        if n_b == 0:
+            return
+        mean_b = float(np.mean(values))
+        m2_b = float(np.sum((values - mean_b) ** 2))
+        n = self.count + n_b
+        d = mean_b - self.mean
+        self.mean += d * n_b / n
+        self._m2 += m2_b + d * d * self.count * n_b / n
+        self.count = n
+
+    @property
+    def std(self) -> float:
+        return (self._m2 / self.count) ** 0.5 if self.count > 1 else 0.0
+
+    def z_score(self, value: float) -> float:
+        s = self.std
+        return (value - self.mean) / s if s > 0 else 0.0
+
+    def get_state(self) -> dict:
+        """Plain-number accumulators, for Pipeline.save_state()."""
+        return {"count": self.count, "mean": self.mean, "m2": self._m2}
+
+    @classmethod
+    def from_state(cls, state: dict) -> RollingStats:
+        stats = cls()
+        stats.count, stats.mean, stats._m2 = int(state["count"]), state["mean"], state["m2"]
+        return stats
+
+
+class WindowedStats:
+    """Same interface as RollingStats, over the last `window` values only."""
+
+    def __init__(self, window: int) -> None:
+        self._values: deque[float] = deque(maxlen=window)
+
+    @property
+    def count(self) -> int:
+        return len(self._values)
+
+    def update(self, value: float) -> None:
+        self._values.append(value)
+
+    def update_batch(self, values: NDArray[np.float64]) -> None:
+        self._values.extend(values.tolist())
+
+    @property
+    def mean(self) -> float:
+        return float(np.mean(self._values)) if self._values else 0.0
+
+    @property
+    def std(self) -> float:
+        return float(np.std(self._values)) if len(self._values) > 1 else 0.0
+
+    def z_score(self, value: float) -> float:
+        s = self.std
+        return (value - self.mean) / s if s > 0 else 0.0
+
+    def get_state(self) -> dict:
+        """Window length and values, oldest first, for Pipeline.save_state()."""
+        return {"window": self._values.maxlen, "values": list(self._values)}
+
+    @classmethod
+    def from_state(cls, state: dict) -> WindowedStats:
+        stats = cls(int(state["window"]))
+        stats._values.extend(state["values"])
+        return stats
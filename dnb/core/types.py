@@ -18,6 +18,8 @@ class EventType(Enum):
     STIM = auto()
     SLOW_WAVE = auto()
     IED = auto()
+    RIPPLE = auto()
+    SPINDLE = auto()
     CUSTOM = auto()
 
 
@@ -75,11 +77,19 @@ class PipelineConfig:
 
     sample_rate: hardware rate (before downsampling).
     channel_id: which hardware channel to read.
+    active_epochs: (start_s, end_s) windows where detection is allowed,
+        e.g. scored NREM periods. None = always active. Outside them the
+        wavelet and baselines keep running, detectors stay quiet.
+    nan_policy: what the pipeline does with NaN/Inf input samples before
+        any module sees them — "hold" (repeat the last finite sample),
+        "zero", or "skip" (drop them; leaves a gap in the timestamps).
     """
     sample_rate: float = 30_000.0
     channel_id: int = 0
     buffer_duration: float = 10.0
     chunk_duration: float = 0.5
+    active_epochs: list[tuple[float, float]] | None = None
+    nan_policy: str = "hold"
 
     @property
     def buffer_samples(self) -> int:
@@ -87,4 +97,9 @@ class PipelineConfig:
 
     @property
     def chunk_samples(self) -> int:
-        return int(self.chunk_duration * self.sample_rate)
\ No newline at end of file
+        return int(self.chunk_duration * self.sample_rate)
+
+    def epoch_active(self, t: float) -> bool:
+        if self.active_epochs is None:
+            return True
+        return any(start <= t < end for start, end in self.active_epochs)
\ No newline at end of file
@@ -1,4 +1,14 @@
+from dnb.engine.consistency import check_rerun_consistency, compare_events
 from dnb.engine.event_bus import EventBus
+from dnb.engine.export import DetectionCsvWriter
 from dnb.engine.pipeline import Pipeline
+from dnb.engine.sliding import run_sliding
 
-__all__ = ["EventBus", "Pipeline"]
\ No newline at end of file
+__all__ = [
+    "DetectionCsvWriter",
+    "EventBus",
+    "Pipeline",
+    "check_rerun_consistency",
+    "compare_events",
+    "run_sliding",
+]
\ No newline at end of file
@@ -0,0 +1,65 @@
+"""Per-chunk CSV export of detector output for offline analysis.
+
+Events only record accepted detections. To see why something was (or
+wasn't) detected, it helps to have the raw detector values over time:
+power, z-scores, activity flags. DetectionCsvWriter writes one row per
+processed chunk with the chunk index, its last timestamp and any
+requested `detector_id:key` values from result.detections.
+"""
+
+from __future__ import annotations
+
+import csv
+import logging
+from pathlib import Path
+
+from dnb.errors import ConfigError
+from dnb.modules.base import ProcessResult
+
+logger = logging.getLogger(__name__)
+
+
+class DetectionCsvWriter:
+    """Result callback that appends one CSV row per chunk.
+
+    Pass it as `result_callback` to Pipeline.run_offline(). Values a
+    detector did not report for a chunk (warmup, disabled) are left empty.
+
+    Args:
+        path: Output .csv path (overwritten).
+        keys: Columns as "detector_id:key", e.g. "ied_monitor:power".
+    """
+
+    def __init__(self, path: str | Path, keys: list[str]) -> None:
+        self._keys: list[tuple[str, str]] = []
+        for k in keys:
+            det_id, sep, field = k.partition(":")
+            if not sep or not det_id or not field:
+                raise ConfigError(f"CSV key {k!r} must look like 'detector_id:key'")
+            self._keys.append((det_id, field))
+        self.path = Path(path)
+        self._file = open(self.path, "w", newline="")
+        self._writer = csv.writer(self._file)
+        self._writer.writerow(["chunk_index", "timestamp"] + list(keys))
+        self.rows = 0
+        logger.info("Detection CSV: %s (%d columns)", self.path, len(keys))
+
+    def __call__(self, result: ProcessResult) -> None:
+        chunk = result.chunk
+        t = float(chunk.timestamps[-1]) if chunk.n_samples > 0 else ""
+        row = [self.rows, t]
+        for det_id, field in self._keys:
+            value = result.detections.get(det_id, {}).get(field)
+            row.append("" if value is None else value)
+        self._writer.writerow(row)
+        self.rows += 1
+
+    def close(self) -> None:
+        if not self._file.closed:
+            self._file.close()
+
+    def __enter__(self) -> DetectionCsvWriter:
+        return self
+
+    def __exit__(self, *exc) -> None:
+        self.close()
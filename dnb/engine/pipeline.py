@@ -5,8 +5,8 @@ ring buffer. Modules transform chunks but never write to the buffer.
 This eliminates double-write bugs.
 
 Flow per chunk:
-    1. Source produces raw chunk
-    2. Downsampler transforms chunk (if present)
+    1. Source produces raw chunk (non-finite samples replaced per nan_policy)
+    2. Chunk transforms run in order (Downsampler, AutoGainFilter, ...)
     3. Pipeline writes (transformed) samples into ring buffer
     4. Wavelet reads from ring buffer
     5. Detectors read wavelet output
@@ -15,22 +15,60 @@ Flow per chunk:
 
 from __future__ import annotations
 
+import json
 import logging
 import signal
 import time
+from collections import deque
 from pathlib import Path
 from typing import Callable
 
 import numpy as np
 
 from dnb.core.ring_buffer import RingBuffer
+from dnb.core.stats import RollingStats, WindowedStats
 from dnb.core.types import DataChunk, Event, EventType, PipelineConfig
 from dnb.engine.event_bus import EventBus, EventCallback
+from dnb.errors import DnbIOError, DnbRuntimeError, ValidationError
 from dnb.modules.base import Module, ProcessResult
 from dnb.sources.base import DataSource
 
 logger = logging.getLogger(__name__)
 
+# save_state() file format; bump when the layout changes
+STATE_VERSION = 2
+_STATS_TYPES = {cls.__name__: cls for cls in (RollingStats, WindowedStats)}
+
+
+def _state_to_json(value):
+    """Module/buffer state → JSON-safe values; arrays keep dtype and shape."""
+    if isinstance(value, np.ndarray):
+        return {"__ndarray__": value.tolist(), "dtype": str(value.dtype),
+                "shape": list(value.shape)}
+    if isinstance(value, (RollingStats, WindowedStats)):
+        return {"__stats__": type(value).__name__, **value.get_state()}
+    if isinstance(value, dict):
+        return {str(k): _state_to_json(v) for k, v in value.items()}
+    if isinstance(value, (list, tuple)):
+        return [_state_to_json(v) for v in value]
+    if isinstance(value, np.generic):
+        return value.item()
+    if value is None or isinstance(value, (bool, int, float, str)):
+        return value
+    raise DnbRuntimeError(f"save_state: cannot store a {type(value).__name__}")
+
+
+def _state_from_json(value):
+    if isinstance(value, dict):
+        if "__ndarray__" in value:
+            return np.array(value["__ndarray__"], dtype=value["dtype"]).reshape(value["shape"])
+        if "__stats__" in value:
+            return _STATS_TYPES[value["__stats__"]].from_state(value)
+        return {k: _state_from_json(v) for k, v in value.items()}
+    if isinstance(value, list):
+        return [_state_from_json(v) for v in value]
+    return value
+
 
 class Pipeline:
     def __init__(
@@ -47,7 +85,47 @@ class Pipeline:
         self._running = False
         self._chunk_count = 0
         self._total_events = 0
-        self._ds_module_idx: int | None = None  # index of downsampler in module list
+        self._transform_idx: list[int] = []  # modules with transforms_chunk
+        self._nonfinite_samples = 0
+        self._last_finite = 0.0
+        self._event_counts: dict[str, int] = {}
+        self._was_active: dict[str, bool] = {}   # per detector id, for onset counts
+        self._final_state: dict | None = None    # snapshot taken at teardown
+        self._pending_state: dict | None = None  # loaded, applied on next setup
+        self.reset_processing_stats()
+
+    # Chunks kept for the p99 latency (min/max/mean cover the whole run)
+    LATENCY_WINDOW = 10_000
+
+    def processing_stats(self) -> dict[str, float]:
+        """Per-chunk processing time (ms) of _process_chunk, source read excluded.
+
+        Keys: chunks, samples, min_ms, mean_ms, max_ms, p99_ms (over the
+        last LATENCY_WINDOW chunks). Times are 0.0 before the first chunk.
+        """
+        n = self._lat_count
+        recent = np.asarray(self._lat_recent)
+        return {
+            "chunks": n,
+            "samples": self._lat_samples,
+            "min_ms": self._lat_min * 1000 if n else 0.0,
+            "mean_ms": self._lat_sum / n * 1000 if n else 0.0,
+            "max_ms": self._lat_max * 1000,
+            "p99_ms": float(np.percentile(recent, 99)) * 1000 if n else 0.0,
+        }
+
+    def reset_processing_stats(self) -> None:
+        self._lat_count = 0
+        self._lat_samples = 0
+        self._lat_sum = 0.0
+        self._lat_min = float("inf")
+        self._lat_max = 0.0
+        self._lat_recent: deque[float] = deque(maxlen=self.LATENCY_WINDOW)
+
+    @property
+    def nonfinite_samples(self) -> int:
+        """NaN/Inf input samples replaced or dropped since the run started."""
+        return self._nonfinite_samples
 
     @property
     def config(self) -> PipelineConfig:
@@ -57,6 +135,120 @@ class Pipeline:
     def event_bus(self) -> EventBus:
         return self._event_bus
 
+    def describe(self) -> str:
+        """Module topology, one line per module in processing order."""
+        lines = [f"Source: {type(self._source).__name__}"]
+        lines += [f"  {i}. {m.describe()}" for i, m in enumerate(self._modules, 1)]
+        return "\n".join(lines)
+
+    def set_stimulation_enabled(self, enabled: bool) -> None:
+        """Global kill switch: forwarded to every module that supports it.
+
+        Safe to call from another thread while the pipeline is running.
+        Processing, detection and logging continue; only stimulation stops.
+        """
+        for module in self._modules:
+            if hasattr(module, "set_stimulation_enabled"):
+                module.set_stimulation_enabled(enabled)
+
+    def trigger_stats(self) -> dict[str, dict[str, int]]:
+        """Per-trigger counters, keyed by the trigger's id."""
+        return {
+            getattr(m, "id", type(m).__name__): m.trigger_stats()
+            for m in self._modules if hasattr(m, "trigger_stats")
+        }
+
+    def _signature(self) -> list[list[str | None]]:
+        return [[type(m).__name__, getattr(m, "id", None)] for m in self._modules]
+
+    def _snapshot(self) -> dict:
+        return {
+            "version": STATE_VERSION,
+            "modules": self._signature(),
+            "sample_rate": self._config.sample_rate,
+            "module_state": [m.get_state() for m in self._modules],
+            "buffer": self._buffer.get_state() if self._buffer is not None else None,
+        }
+
+    def save_state(self, path: str | Path) -> None:
+        """Write filter delay lines, baselines and the ring buffer to `path`.
+
+        While running this is the current state; after a run, the state at
+        its end. Load it into a pipeline with the same modules to resume
+        without re-learning baselines. Timers (backoff, cooldown, rate
+        windows) are not saved, since the source clock restarts.
+
+        The file is JSON with a "version" key; arrays are stored as nested
+        lists with their dtype and shape, so floats round-trip exactly.
+        """
+        state = self._snapshot() if self._running else self._final_state
+        if state is None:
+            raise DnbRuntimeError("No state to save — run the pipeline first")
+        text = json.dumps(_state_to_json(state))
+        try:
+            Path(path).write_text(text, encoding="utf-8")
+        except OSError as e:
+            raise DnbIOError(f"Could not write state to {path}: {e}") from e
+        logger.info("Saved pipeline state to %s", path)
+
+    def load_state(self, path: str | Path) -> None:
+        """Load a save_state() file; it is applied when the next run starts.
+
+        Raises:
+            DnbIOError: the file can't be read or isn't a state file.
+            ValidationError: wrong format version, or the saved modules
+                (types and ids, in order) differ from this pipeline's.
+        """
+        try:
+            state = json.loads(Path(path).read_text(encoding="utf-8"))
+        except (OSError, UnicodeDecodeError, json.JSONDecodeError) as e:
+            raise DnbIOError(f"Could not read state from {path}: {e}") from e
+        if not isinstance(state, dict) or "version" not in state:
+            raise DnbIOError(f"{path} is not a pipeline state file")
+        if state["version"] != STATE_VERSION:
+            raise ValidationError(
+                f"State in {path} has format version {state['version']}, "
+                f"this version reads {STATE_VERSION}"
+            )
+        saved, ours = state.get("modules", []), self._signature()
+        mismatches = [
+            f"  {i}. saved {s[0]} '{s[1]}', have {o[0]} '{o[1]}'"
+            for i, (s, o) in enumerate(zip(saved, ours), 1) if s != o
+        ]
+        if len(saved) != len(ours):
+            mismatches.append(f"  saved {len(saved)} modules, have {len(ours)}")
+        if mismatches:
+            raise ValidationError(
+                f"State in {path} was saved for different modules:\n" + "\n".join(mismatches)
+            )
+        self._pending_state = _state_from_json(state)
+
+    def _apply_state(self, state: dict) -> None:
+        if abs(state["sample_rate"] - self._config.sample_rate) > 0.1:
+            raise ValidationError(
+                f"State was saved at {state['sample_rate']:.0f} Hz, "
+                f"source runs at {self._config.sample_rate:.0f} Hz"
+            )
+        for module, module_state in zip(self._modules, state["module_state"]):
+            module.set_state(module_state)
+        if state["buffer"] is not None:
+            try:
+                self._buffer.set_state(state["buffer"])
+            except ValueError as e:
+                raise ValidationError(f"Saved ring buffer does not fit: {e}") from e
+        logger.info("Pipeline: resumed from saved state")
+
+    def event_counts(self) -> dict[str, int]:
+        """Events published and detections made since the run started.
+
+        For a quick health check. Keyed by event type ("STIM"), by type
+        and source ("STIM:trigger", "RIPPLE:ripple") — the source being
+        the event's trigger_id or detector_id — and by detector
+        ("DETECTION:slow_wave"): onsets of result.detections[id]["active"],
+        i.e. chunks where a detector turns active.
+        """
+        return dict(self._event_counts)
+
     def on_event(self, event_type: EventType | str | None, callback: EventCallback) -> None:
         if isinstance(event_type, str):
             event_type = EventType[event_type.upper()]
@@ -69,15 +261,16 @@ class Pipeline:
         if resolved is not None:
             self._config = resolved
 
-        # Configure all modules, find downsampler
+        # Configure all modules, find chunk transforms
         from dnb.modules.downsampler import Downsampler
         analysis_rate = self._config.sample_rate
-        self._ds_module_idx = None
+        self._transform_idx = []
 
         for i, module in enumerate(self._modules):
             module.configure(self._config)
+            if module.transforms_chunk:
+                self._transform_idx.append(i)
             if isinstance(module, Downsampler):
-                self._ds_module_idx = i
                 analysis_rate = module.actual_rate
 
         # Single ring buffer at the analysis rate
@@ -86,18 +279,82 @@ class Pipeline:
 
         self._chunk_count = 0
         self._total_events = 0
+        self._nonfinite_samples = 0
+        self._last_finite = 0.0
+        self._event_counts = {}
+        self._was_active = {}
+        if self._pending_state is not None:
+            state, self._pending_state = self._pending_state, None
+            self._apply_state(state)
+        self.reset_processing_stats()
         logger.info(
             "Pipeline: %d modules, buffer=%.1fs (%d samples @ %.0f Hz), chunk=%.3fs",
             len(self._modules), self._config.buffer_duration,
             buf_capacity, analysis_rate, self._config.chunk_duration,
         )
 
+    def _sanitize(self, chunk: DataChunk) -> DataChunk:
+        """Apply nan_policy to non-finite samples (dropped packets, saturation).
+
+        One NaN would otherwise poison every filter state and running
+        baseline downstream for the rest of the session.
+        """
+        samples = chunk.samples
+        bad = ~np.isfinite(samples)
+        n_bad = int(np.count_nonzero(bad))
+        if n_bad == 0:
+            if chunk.n_samples > 0:
+                self._last_finite = float(samples[-1])
+            return chunk
+        if self._nonfinite_samples == 0:
+            logger.warning(
+                "Pipeline: non-finite input samples at t=%.3fs — applying nan_policy=%s",
+                float(chunk.timestamps[np.argmax(bad)]), self._config.nan_policy,
+            )
+        self._nonfinite_samples += n_bad
+
+        timestamps = chunk.timestamps
+        if self._config.nan_policy == "skip":
+            samples, timestamps = samples[~bad], timestamps[~bad]
+        elif self._config.nan_policy == "zero":
+            samples = np.where(bad, 0.0, samples)
+        else:
+            # Hold: index of the latest finite sample at or before each position
+            idx = np.where(bad, -1, np.arange(samples.shape[0]))
+            np.maximum.accumulate(idx, out=idx)
+            samples = np.where(idx >= 0, samples[np.maximum(idx, 0)], self._last_finite)
+        if samples.shape[0] > 0 and np.isfinite(samples[-1]):
+            self._last_finite = float(samples[-1])
+        return DataChunk(
+            samples=samples,
+            timestamps=timestamps,
+            channel_id=chunk.channel_id,
+            sample_rate=chunk.sample_rate,
+        )
+
     def _process_chunk(self, chunk: DataChunk) -> ProcessResult:
-        result = ProcessResult(chunk=chunk, ring_buffer=self._buffer)
+        t0 = time.perf_counter()
+        n_in = chunk.n_samples
+        result = self._run_modules(chunk)
+        dt = time.perf_counter() - t0
+        self._lat_count += 1
+        self._lat_samples += n_in
+        self._lat_sum += dt
+        self._lat_min = min(self._lat_min, dt)
+        self._lat_max = max(self._lat_max, dt)
+        self._lat_recent.append(dt)
+        return result
+
+    def _run_modules(self, raw_chunk: DataChunk) -> ProcessResult:
+        chunk = self._sanitize(raw_chunk)
+        result = ProcessResult(chunk=chunk, ring_buffer=self._buffer, raw_chunk=raw_chunk)
+        if chunk.n_samples == 0:
+            return result  # everything skipped
+        result.epoch_active = self._config.epoch_active(float(chunk.timestamps[-1]))
 
-        # Run downsampler first (if present) to transform the chunk
-        if self._ds_module_idx is not None:
-            result = self._modules[self._ds_module_idx].process(result)
+        # Run chunk transforms first (downsampler, gain, ...), in list order
+        for i in self._transform_idx:
+            result = self._modules[i].process(result)
 
         # Write the (possibly decimated) chunk into the ring buffer.
         # This is the ONLY write point.
@@ -105,17 +362,29 @@ class Pipeline:
 
         # Run remaining modules (wavelet, detectors, trigger)
         for i, module in enumerate(self._modules):
-            if i == self._ds_module_idx:
+            if i in self._transform_idx:
                 continue  # already ran
             result = module.process(result)
 
         for event in result.events:
             self._event_bus.publish(event)
+            self._count(event.event_type.name)
+            src = event.metadata.get("trigger_id", event.metadata.get("detector_id"))
+            if src is not None:
+                self._count(f"{event.event_type.name}:{src}")
+        for det_id, state in result.detections.items():
+            active = bool(state.get("active", False))
+            if active and not self._was_active.get(det_id, False):
+                self._count(f"DETECTION:{det_id}")
+            self._was_active[det_id] = active
 
         self._chunk_count += 1
         self._total_events += len(result.events)
         return result
 
+    def _count(self, key: str) -> None:
+        self._event_counts[key] = self._event_counts.get(key, 0) + 1
+
     def run_online(self) -> None:
         self._setup()
         self._running = True
@@ -147,6 +416,7 @@ class Pipeline:
         self,
         output_path: str | Path | None = None,
         progress_callback: Callable[[float], None] | None = None,
+        result_callback: Callable[[ProcessResult], None] | None = None,
     ) -> list[Event]:
         self._setup()
         self._running = True
@@ -162,6 +432,8 @@ class Pipeline:
                     break
                 result = self._process_chunk(chunk)
                 all_events.extend(result.events)
+                if result_callback is not None:
+                    result_callback(result)
 
                 if progress_callback is not None:
                     prog = getattr(self._source, "progress", 0.0)
@@ -180,8 +452,33 @@ class Pipeline:
     def stop(self) -> None:
         self._running = False
 
+    def reset(self) -> None:
+        """Clear all processing state: ring buffer, module state, counters.
+
+        run_online()/run_offline() already start clean; use this when
+        feeding chunks by hand and moving on to a new file or trial, so
+        filter state and baselines from the previous one don't leak in.
+        Latency stats restart too, and a state loaded with load_state()
+        but not yet applied is discarded — reset means a clean start.
+        """
+        if self._pending_state is not None:
+            logger.info("Pipeline: reset discards the loaded, not yet applied state")
+            self._pending_state = None
+        if self._buffer is not None:
+            self._buffer.clear()
+        for module in self._modules:
+            module.reset()
+        self._chunk_count = 0
+        self._total_events = 0
+        self._nonfinite_samples = 0
+        self._last_finite = 0.0
+        self._event_counts = {}
+        self._was_active = {}
+        self.reset_processing_stats()
+
     def _teardown(self) -> None:
         self._source.close()
+        self._final_state = self._snapshot()
         for module in self._modules:
             module.reset()
         self._running = False
@@ -0,0 +1,29 @@
+"""Exception types raised by DNB.
+
+Everything derives from DnbError, so library callers can catch DNB
+failures in one place and match on the specific subclass. Each also
+subclasses the builtin it replaced, so existing `except ValueError` /
+`except OSError` / `except RuntimeError` handlers keep working.
+"""
+
+from __future__ import annotations
+
+
+class DnbError(Exception):
+    """Base class for all DNB errors."""
+
+
+class ConfigError(DnbError, ValueError):
+    """Config is malformed, inconsistent, or names something unknown."""
+
+
+class DnbIOError(DnbError, OSError):
+    """A config or data file is missing or unreadable."""
+
+
+class ValidationError(DnbError, ValueError):
+    """Input data has the wrong format, shape, or contents."""
+
+
+class DnbRuntimeError(DnbError, RuntimeError):
+    """Operation not valid in the current state (e.g. source not connected)."""
@@ -1,7 +1,15 @@
 from dnb.modules.amplitude_monitor import AmplitudeMonitor
 from dnb.modules.audio_stim import AudioStimulator
+from dnb.modules.auto_gain import AutoGainFilter
 from dnb.modules.base import Module, ProcessResult
+from dnb.modules.burst import BurstDetector, RippleDetector, SpindleDetector
+from dnb.modules.cross_corr import CrossCorrMonitor
 from dnb.modules.downsampler import Downsampler
+from dnb.modules.envelope import EnvelopeDetector
+from dnb.modules.filters import (
+    BaselineRemovalFilter, CombNotchFilter, IirFilter, MovingAverageFilter, NotchFilter,
+    SosFilter, filter_offline,
+)
 from dnb.modules.stim_scheduler import StimScheduler
 from dnb.modules.stim_trigger import StimTrigger
 from dnb.modules.twave_detector import TWaveDetector
@@ -10,11 +18,24 @@ from dnb.modules.wavelet import WaveletConvolution
 __all__ = [
     "AmplitudeMonitor",
     "AudioStimulator",
+    "AutoGainFilter",
+    "BaselineRemovalFilter",
+    "BurstDetector",
+    "CombNotchFilter",
+    "CrossCorrMonitor",
     "Downsampler",
+    "EnvelopeDetector",
+    "IirFilter",
     "Module",
+    "MovingAverageFilter",
+    "NotchFilter",
     "ProcessResult",
+    "RippleDetector",
+    "SosFilter",
+    "SpindleDetector",
     "StimScheduler",
     "StimTrigger",
     "TWaveDetector",
     "WaveletConvolution",
+    "filter_offline",
 ]
\ No newline at end of file
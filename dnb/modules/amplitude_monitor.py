@@ -1,7 +1,15 @@
 """Amplitude monitor — IED inhibition via broadband power, single channel.
 
 Filter built lazily from actual chunk sample rate.
-Rolling z-score baseline (Welford). Active chunks excluded from baseline.
+Rolling z-score baseline (Welford, or over the last baseline_chunks
+chunks so it follows slow drift). Active chunks excluded from baseline.
+Optionally the baseline is frozen after a learning period, so later large
+events can't inflate it and desensitise detection.
+
+With a release level set (release_n_std / release_threshold), detection
+has hysteresis: it turns on above the threshold and stays on until the
+level falls below the lower release level, so power hovering around the
+threshold doesn't flicker on and off.
 """
 
 from __future__ import annotations
@@ -11,34 +19,17 @@ import logging
 import numpy as np
 from scipy.signal import butter, sosfilt
 
+from dnb.core.stats import RollingStats, WindowedStats
 from dnb.core.types import PipelineConfig
-from dnb.modules.base import Module, ProcessResult
+from dnb.errors import ConfigError
+from dnb.modules.base import Module, ProcessResult, describe_band
 
 logger = logging.getLogger(__name__)
 
 
-class _RollingStats:
-    def __init__(self) -> None:
-        self.count = 0
-        self.mean = 0.0
-        self._m2 = 0.0
-
-    def update(self, value: float) -> None:
-        self.count += 1
-        d = value - self.mean
-        self.mean += d / self.count
-        self._m2 += d * (value - self.mean)
-
-    @property
-    def std(self) -> float:
-        return (self._m2 / self.count) ** 0.5 if self.count > 1 else 0.0
-
-    def z_score(self, value: float) -> float:
-        s = self.std
-        return (value - self.mean) / s if s > 0 else 0.0
-
-
 class AmplitudeMonitor(Module):
+    _state_attrs = ("_chunks_seen", "_stats")
+
     def __init__(
         self,
         id: str = "ied_monitor",
@@ -47,7 +38,12 @@ class AmplitudeMonitor(Module):
         adaptive_n_std: float = 3.0,
         warmup_chunks: int = 20,
         filter_order: int = 4,
-        baseline_chunks: int = 100,  # compat, ignored
+        baseline_chunks: int | None = None,
+        band_name: str | None = None,
+        role: str | None = None,
+        freeze_statistics_after_s: float | None = None,
+        release_n_std: float | None = None,
+        release_threshold: float | None = None,
     ) -> None:
         self.id = id
         self._freq_range = freq_range
@@ -55,10 +51,52 @@ class AmplitudeMonitor(Module):
         self._adaptive_n_std = adaptive_n_std
         self._warmup_chunks = warmup_chunks
         self._filter_order = filter_order
+        self.band_name = band_name
+        self.role = role
         self._sos: np.ndarray | None = None
         self._built_for_rate: float = 0.0
         self._chunks_seen: int = 0
-        self._stats = _RollingStats()
+        if baseline_chunks is not None and baseline_chunks < 2:
+            raise ConfigError(f"AmplitudeMonitor '{id}': baseline_chunks must be >= 2")
+        self._baseline_chunks = baseline_chunks
+        self._stats = self._new_stats()
+        self._freeze_after_s = freeze_statistics_after_s
+        self._t_first: float | None = None
+        self._t_last: float = 0.0
+        if release_n_std is not None and release_n_std >= adaptive_n_std:
+            raise ConfigError(f"AmplitudeMonitor '{id}': release_n_std must be < adaptive_n_std")
+        if (release_threshold is not None and threshold is not None
+                and release_threshold >= threshold):
+            raise ConfigError(f"AmplitudeMonitor '{id}': release_threshold must be < threshold")
+        self._release_n_std = release_n_std
+        self._release_threshold = release_threshold
+        self._active = False
+
+    def _new_stats(self) -> RollingStats | WindowedStats:
+        if self._baseline_chunks is None:
+            return RollingStats()
+        return WindowedStats(self._baseline_chunks)
+
+    @property
+    def sos(self) -> np.ndarray | None:
+        """Bandpass coefficients in use (None until the first chunk)."""
+        return None if self._sos is None else self._sos.copy()
+
+    @property
+    def statistics_frozen(self) -> bool:
+        return self._freeze_after_s is not None and self._t_first is not None and (
+            self._t_last - self._t_first >= self._freeze_after_s
+        )
+
+    def _update_baseline(self, power: float) -> None:
+        if not self.statistics_frozen:
+            self._stats.update(power)
+
+    def describe(self) -> str:
+        return (
+            f"AmplitudeMonitor '{self.id}': "
+            f"{describe_band(self._freq_range, self.band_name, self.role)}"
+        )
 
     def configure(self, config: PipelineConfig) -> None:
         logger.info(
@@ -95,25 +133,38 @@ class AmplitudeMonitor(Module):
         filtered = sosfilt(self._sos, chunk.samples)
         power = float(np.sqrt(np.mean(filtered ** 2)))
         self._chunks_seen += 1
+        if self._t_first is None:
+            self._t_first = float(chunk.timestamps[0])
+        self._t_last = float(chunk.timestamps[-1])
 
         if self._chunks_seen <= self._warmup_chunks:
-            self._stats.update(power)
+            self._update_baseline(power)
             result.detections[self.id] = {"active": False, "power": power, "warming_up": True}
             return result
 
+        z = self._stats.z_score(power) if self._stats.count > 0 else None
         if self._threshold is not None:
-            active = power > self._threshold
+            level, on, off = power, self._threshold, self._release_threshold
         else:
-            active = self._stats.z_score(power) > self._adaptive_n_std if self._stats.count > 0 else False
+            level, on, off = z, self._adaptive_n_std, self._release_n_std
+        if level is None:
+            active = False
+        else:
+            # Once on, stay on until the level drops below the release level
+            active = level > (off if self._active and off is not None else on)
+        self._active = active
 
         if not active:
-            self._stats.update(power)
+            self._update_baseline(power)
 
-        result.detections[self.id] = {"active": active, "power": power}
+        result.detections[self.id] = {"active": active, "power": power, "z_score": z}
         return result
 
     def reset(self) -> None:
         self._chunks_seen = 0
-        self._stats = _RollingStats()
+        self._stats = self._new_stats()
+        self._t_first = None
+        self._t_last = 0.0
         self._sos = None
-        self._built_for_rate = 0.0
\ No newline at end of file
+        self._built_for_rate = 0.0
+        self._active = False
\ No newline at end of file
@@ -0,0 +1,120 @@
+"""Automatic gain normalization — scales the signal toward a target RMS.
+
+Absolute amplitudes differ widely between electrodes and sessions, so a
+fixed amp_min/amp_max only fits the recording it was tuned on. This
+stage tracks the rolling RMS of the incoming signal and multiplies it by
+a gain that moves slowly toward target_rms / rms, so downstream
+thresholds are in "normalized µV" and portable across sessions.
+
+The gain time constant (gain_tau_s) is much longer than a slow wave, so
+individual transients are not flattened — only the overall level is.
+
+Like the Downsampler, this transforms the chunk before the pipeline
+writes it to the ring buffer. It does NOT write to the buffer itself.
+"""
+
+from __future__ import annotations
+
+import logging
+from math import exp, sqrt
+
+import numpy as np
+
+from dnb.core.types import DataChunk, PipelineConfig
+from dnb.modules.base import Module, ProcessResult
+
+logger = logging.getLogger(__name__)
+
+
+class AutoGainFilter(Module):
+    """Rolling-RMS gain normalization.
+
+    Args:
+        id: Key in result.detections where the current gain is reported.
+        target_rms: RMS the output is scaled toward (same units as input).
+        rms_window_s: Time constant of the rolling RMS estimate.
+        gain_tau_s: Time constant of gain changes. Long values keep the
+            gain steady across individual waves.
+        min_gain: Lower bound on the gain.
+        max_gain: Upper bound on the gain (stops flat/disconnected
+            channels being blown up).
+    """
+
+    transforms_chunk = True
+    _state_attrs = ("_mean_sq", "_gain")
+
+    def __init__(
+        self,
+        id: str = "auto_gain",
+        target_rms: float = 50.0,
+        rms_window_s: float = 10.0,
+        gain_tau_s: float = 30.0,
+        min_gain: float = 0.01,
+        max_gain: float = 100.0,
+    ) -> None:
+        self.id = id
+        self._target_rms = target_rms
+        self._rms_window_s = rms_window_s
+        self._gain_tau_s = gain_tau_s
+        self._min_gain = min_gain
+        self._max_gain = max_gain
+
+        self._mean_sq: float | None = None
+        self._gain: float = 1.0
+
+    @property
+    def gain(self) -> float:
+        return self._gain
+
+    def describe(self) -> str:
+        return f"AutoGainFilter: target RMS {self._target_rms:g}, τ={self._gain_tau_s:g}s"
+
+    def configure(self, config: PipelineConfig) -> None:
+        self._mean_sq = None
+        self._gain = 1.0
+        logger.info(
+            "AutoGainFilter: target_rms=%.1f, rms_window=%.1fs, gain_tau=%.1fs",
+            self._target_rms, self._rms_window_s, self._gain_tau_s,
+        )
+
+    def process(self, result: ProcessResult) -> ProcessResult:
+        chunk = result.chunk
+        if chunk.n_samples == 0:
+            return result
+
+        duration = chunk.n_samples / chunk.sample_rate
+        chunk_mean_sq = float(np.mean(chunk.samples ** 2))
+
+        if self._mean_sq is None:
+            # First chunk seeds both the RMS and the gain
+            self._mean_sq = chunk_mean_sq
+            self._gain = self._clip(self._target_gain())
+        else:
+            alpha = 1.0 - exp(-duration / self._rms_window_s)
+            self._mean_sq += alpha * (chunk_mean_sq - self._mean_sq)
+            beta = 1.0 - exp(-duration / self._gain_tau_s)
+            self._gain = self._clip(self._gain + beta * (self._target_gain() - self._gain))
+
+        result.chunk = DataChunk(
+            samples=chunk.samples * self._gain,
+            timestamps=chunk.timestamps,
+            channel_id=chunk.channel_id,
+            sample_rate=chunk.sample_rate,
+        )
+        result.detections[self.id] = {
+            "active": False,
+            "gain": self._gain,
+            "rms": sqrt(self._mean_sq),
+        }
+        return result
+
+    def _target_gain(self) -> float:
+        rms = sqrt(self._mean_sq)
+        return self._target_rms / rms if rms > 0 else self._max_gain
+
+    def _clip(self, gain: float) -> float:
+        return min(max(gain, self._min_gain), self._max_gain)
+
+    def reset(self) -> None:
+        self._mean_sq = None
+        self._gain = 1.0
@@ -2,9 +2,10 @@
 
 from __future__ import annotations
 
+import copy
 from abc import ABC, abstractmethod
 from dataclasses import dataclass, field
-from typing import TYPE_CHECKING
+from typing import TYPE_CHECKING, Any
 
 from dnb.core.types import DataChunk, Event, PipelineConfig, WaveletResult
 
@@ -16,7 +17,11 @@ if TYPE_CHECKING:
 class ProcessResult:
     """Output of a module's process() call.
 
-    chunk: single-channel DataChunk (samples is 1D).
+    chunk: single-channel DataChunk (samples is 1D). After the chunk
+        transforms (Downsampler, AutoGainFilter, ...) this is the
+        processed signal that was written to the ring buffer.
+    raw_chunk: the chunk exactly as the source produced it, before any
+        transform. Set by the pipeline; never modified.
     """
     chunk: DataChunk | None
     wavelet: WaveletResult | None = None
@@ -25,13 +30,48 @@ class ProcessResult:
     detections: dict[str, dict] = field(default_factory=dict)
     ring_buffer: RingBuffer | None = None
     original_sample_rate: float | None = None
+    epoch_active: bool = True
+    raw_chunk: DataChunk | None = None
 
 
 class Module(ABC):
+    # True for modules that rewrite result.chunk (Downsampler, gain,
+    # filters). The pipeline runs these before the ring buffer write.
+    transforms_chunk: bool = False
+    # Attributes holding adapted state (filter delay lines, baselines)
+    # that Pipeline.save_state() carries across a restart. Times are left
+    # out: the source clock restarts with the process.
+    _state_attrs: tuple[str, ...] = ()
+
     @abstractmethod
     def configure(self, config: PipelineConfig) -> None: ...
 
     @abstractmethod
     def process(self, result: ProcessResult) -> ProcessResult: ...
 
-    def reset(self) -> None: ...
\ No newline at end of file
+    def reset(self) -> None: ...
+
+    def get_state(self) -> dict[str, Any]:
+        """Copy of the attributes named in _state_attrs."""
+        return {name: copy.deepcopy(getattr(self, name)) for name in self._state_attrs}
+
+    def set_state(self, state: dict[str, Any]) -> None:
+        """Restore what get_state() returned. Call after configure()."""
+        for name in self._state_attrs:
+            if name in state:
+                setattr(self, name, copy.deepcopy(state[name]))
+
+    def describe(self) -> str:
+        """One-line summary for Pipeline.describe()."""
+        return type(self).__name__
+
+
+def describe_band(
+    freq_range: tuple[float, float], band_name: str | None, role: str | None,
+) -> str:
+    """Format a frequency band with its optional name and role."""
+    text = f"{freq_range[0]:g}–{freq_range[1]:g} Hz"
+    tags = [t for t in (band_name, role) if t]
+    if tags:
+        text += f" [{', '.join(tags)}]"
+    return text
\ No newline at end of file
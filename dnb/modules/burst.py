@@ -0,0 +1,188 @@
+"""Burst detectors — envelope above threshold for a bounded duration.
+
+Extends EnvelopeDetector with duration gating: a burst starts when the
+envelope z-score rises above z_threshold and ends when it falls back.
+Bursts lasting between min_duration_ms and max_duration_ms emit one
+event (timestamp = burst start, duration = burst length); shorter blips
+and longer sustained power (e.g. artifacts) are ignored. Bursts are
+tracked per sample across chunk boundaries.
+
+Reports into result.detections[id], on top of EnvelopeDetector's keys:
+    active            — inside a burst at the latest sample
+    detected          — a burst within the duration bounds ended this chunk
+    detected_duration_ms — length of that burst (None if none ended)
+    burst_duration_ms — length of the ongoing burst so far (None outside)
+"""
+
+from __future__ import annotations
+
+import logging
+
+import numpy as np
+from numpy.typing import NDArray
+
+from dnb.core.types import Event, EventType
+from dnb.errors import ConfigError
+from dnb.modules.base import ProcessResult
+from dnb.modules.envelope import EnvelopeDetector
+
+logger = logging.getLogger(__name__)
+
+
+class BurstDetector(EnvelopeDetector):
+    """Envelope burst detector with duration bounds.
+
+    Args:
+        min_duration_ms: Shortest burst reported.
+        max_duration_ms: Longest burst reported.
+        event_type: EventType of emitted events.
+        Other args as EnvelopeDetector.
+    """
+
+    def __init__(
+        self,
+        id: str = "burst",
+        freq_range: tuple[float, float] = (0.5, 4.0),
+        z_threshold: float = 3.0,
+        min_duration_ms: float = 20.0,
+        max_duration_ms: float = 200.0,
+        event_type: EventType = EventType.CUSTOM,
+        smoothing_hz: float | None = None,
+        warmup_chunks: int = 20,
+        filter_order: int = 2,
+        band_name: str | None = None,
+        role: str | None = None,
+    ) -> None:
+        super().__init__(
+            id=id, freq_range=freq_range, z_threshold=z_threshold,
+            smoothing_hz=smoothing_hz, warmup_chunks=warmup_chunks,
+            filter_order=filter_order, band_name=band_name, role=role,
+        )
+        if not 0.0 <= min_duration_ms <= max_duration_ms:
+            raise ConfigError(
+                f"{type(self).__name__} '{id}': need 0 <= min_duration_ms <= max_duration_ms"
+            )
+        self._min_duration_s = min_duration_ms / 1000.0
+        self._max_duration_s = max_duration_ms / 1000.0
+        self._event_type = event_type
+
+        self._burst_start: float | None = None
+        self._burst_peak_z: float = 0.0
+        self._detected_duration_s: float | None = None
+
+    def _detect(
+        self, result: ProcessResult,
+        envelope: NDArray[np.float64], z: NDArray[np.float64],
+    ) -> bool:
+        chunk = result.chunk
+        above = z > self._z_threshold
+        n = above.shape[0]
+        detected = False
+        self._detected_duration_s = None
+
+        i = 0
+        while i < n:
+            if self._burst_start is None:
+                rising = np.flatnonzero(above[i:])
+                if rising.shape[0] == 0:
+                    break
+                i += int(rising[0])
+                self._burst_start = float(chunk.timestamps[i])
+                self._burst_peak_z = float(z[i])
+            else:
+                falling = np.flatnonzero(~above[i:])
+                if falling.shape[0] == 0:
+                    self._burst_peak_z = max(self._burst_peak_z, float(np.max(z[i:])))
+                    break
+                end = i + int(falling[0])
+                if end > i:
+                    self._burst_peak_z = max(self._burst_peak_z, float(np.max(z[i:end])))
+                detected |= self._end_burst(result, float(chunk.timestamps[end]))
+                i = end
+
+        active = self._burst_start is not None
+        result.detections[self.id] = {
+            "active": active,
+            "detected": detected,
+            "detected_duration_ms": (
+                self._detected_duration_s * 1000 if detected else None
+            ),
+            "envelope": float(envelope[-1]),
+            "envelope_z": float(z[-1]),
+            "burst_duration_ms": (
+                (float(chunk.timestamps[-1]) - self._burst_start) * 1000 if active else None
+            ),
+        }
+        return bool(np.any(above))
+
+    def _end_burst(self, result: ProcessResult, t_end: float) -> bool:
+        """Close the current burst; emit an event if its length is in bounds."""
+        t_start = self._burst_start
+        duration = t_end - t_start
+        self._burst_start = None
+        if not self._min_duration_s <= duration <= self._max_duration_s:
+            return False
+        self._detected_duration_s = duration
+        result.events.append(Event(
+            event_type=self._event_type,
+            timestamp=t_start,
+            channel_id=result.chunk.channel_id,
+            duration=duration,
+            metadata={
+                "detector_id": self.id,
+                "duration_ms": duration * 1000,
+                "peak_z": self._burst_peak_z,
+            },
+        ))
+        return True
+
+    def reset(self) -> None:
+        super().reset()
+        self._burst_start = None
+        self._burst_peak_z = 0.0
+        self._detected_duration_s = None
+
+
+class RippleDetector(BurstDetector):
+    """Sharp-wave ripple detector: 80–250 Hz bursts of 20–200 ms.
+
+    Needs an analysis rate above twice the upper band edge (≥ 1 kHz for
+    the default band); at 500 Hz it disables itself with a warning.
+    """
+
+    def __init__(
+        self,
+        id: str = "ripple",
+        freq_range: tuple[float, float] = (80.0, 250.0),
+        z_threshold: float = 3.0,
+        min_duration_ms: float = 20.0,
+        max_duration_ms: float = 200.0,
+        **kwargs,
+    ) -> None:
+        super().__init__(
+            id=id, freq_range=freq_range, z_threshold=z_threshold,
+            min_duration_ms=min_duration_ms, max_duration_ms=max_duration_ms,
+            event_type=EventType.RIPPLE, **kwargs,
+        )
+
+
+class SpindleDetector(BurstDetector):
+    """Sleep-spindle detector: 11–16 Hz bursts of 0.5–2 s.
+
+    A brief 13 Hz blip shorter than min_duration_ms is not a spindle.
+    """
+
+    def __init__(
+        self,
+        id: str = "spindle",
+        freq_range: tuple[float, float] = (11.0, 16.0),
+        z_threshold: float = 2.0,
+        min_duration_ms: float = 500.0,
+        max_duration_ms: float = 2000.0,
+        **kwargs,
+    ) -> None:
+        super().__init__(
+            id=id, freq_range=freq_range, z_threshold=z_threshold,
+            min_duration_ms=min_duration_ms, max_duration_ms=max_duration_ms,
+            event_type=EventType.SPINDLE, **kwargs,
+        )
@@ -0,0 +1,146 @@
+"""Cross-correlation monitor — lag between two frequency bands, single channel.
+
+Diagnostic for latency / alignment checks between bands. Both bands are
+bandpassed from the same chunk (causal, filter state carried across
+chunks) and an FFT cross-correlation over a rolling window gives the lag
+at which they align best.
+
+Reports into result.detections[id]:
+    best_lag_ms — positive when band_b lags band_a
+    max_corr    — normalized correlation at that lag (-1..1)
+
+Never 'active': this is a diagnostic, not an activation/inhibition source.
+"""
+
+from __future__ import annotations
+
+import logging
+
+import numpy as np
+from scipy.signal import butter, correlate, correlation_lags, sosfilt
+
+from dnb.core.types import PipelineConfig
+from dnb.modules.base import Module, ProcessResult
+
+logger = logging.getLogger(__name__)
+
+
+class CrossCorrMonitor(Module):
+    """Rolling cross-correlation lag between two bandpassed copies of the signal.
+
+    Args:
+        id: Monitor identifier (key in result.detections).
+        band_a: (lo, hi) Hz of the reference band.
+        band_b: (lo, hi) Hz of the compared band.
+        window_s: Rolling window length for the correlation.
+        max_lag_s: Largest lag (either direction) searched.
+        filter_order: Butterworth order of each bandpass.
+    """
+
+    _state_attrs = (
+        "_sos_a", "_sos_b", "_zi_a", "_zi_b", "_built_for_rate", "_buf_a", "_buf_b",
+    )
+
+    def __init__(
+        self,
+        id: str = "xcorr",
+        band_a: tuple[float, float] = (0.5, 2.0),
+        band_b: tuple[float, float] = (0.5, 4.0),
+        window_s: float = 2.0,
+        max_lag_s: float = 0.25,
+        filter_order: int = 2,
+    ) -> None:
+        self.id = id
+        self._band_a = band_a
+        self._band_b = band_b
+        self._window_s = window_s
+        self._max_lag_s = max_lag_s
+        self._filter_order = filter_order
+
+        self._sos_a: np.ndarray | None = None
+        self._sos_b: np.ndarray | None = None
+        self._zi_a: np.ndarray | None = None
+        self._zi_b: np.ndarray | None = None
+        self._built_for_rate: float = 0.0
+        self._buf_a = np.zeros(0)
+        self._buf_b = np.zeros(0)
+
+    def describe(self) -> str:
+        return (
+            f"CrossCorrMonitor '{self.id}': {self._band_a[0]:g}–{self._band_a[1]:g} Hz "
+            f"vs {self._band_b[0]:g}–{self._band_b[1]:g} Hz"
+        )
+
+    def configure(self, config: PipelineConfig) -> None:
+        logger.info(
+            "CrossCorrMonitor '%s': (%.1f,%.1f) vs (%.1f,%.1f) Hz, window=%.1fs, max_lag=%.0f ms",
+            self.id, *self._band_a, *self._band_b,
+            self._window_s, self._max_lag_s * 1000,
+        )
+
+    def _build_filters(self, sample_rate: float) -> None:
+        nyq = sample_rate / 2.0
+        for lo, hi in (self._band_a, self._band_b):
+            if not 0.0 < lo < hi < nyq:
+                logger.warning(
+                    "CrossCorrMonitor '%s': band (%.1f,%.1f) invalid at %.0f Hz — disabling",
+                    self.id, lo, hi, sample_rate,
+                )
+                self._sos_a = self._sos_b = None
+                return
+        self._sos_a = butter(self._filter_order, self._band_a, btype="band",
+                             output="sos", fs=sample_rate)
+        self._sos_b = butter(self._filter_order, self._band_b, btype="band",
+                             output="sos", fs=sample_rate)
+        self._zi_a = np.zeros((self._sos_a.shape[0], 2))
+        self._zi_b = np.zeros((self._sos_b.shape[0], 2))
+        self._buf_a = np.zeros(0)
+        self._buf_b = np.zeros(0)
+        self._built_for_rate = sample_rate
+
+    def process(self, result: ProcessResult) -> ProcessResult:
+        chunk = result.chunk
+        if self._sos_a is None or abs(chunk.sample_rate - self._built_for_rate) > 0.1:
+            self._build_filters(chunk.sample_rate)
+        if self._sos_a is None:
+            result.detections[self.id] = {"active": False, "best_lag_ms": None, "max_corr": None}
+            return result
+
+        fa, self._zi_a = sosfilt(self._sos_a, chunk.samples, zi=self._zi_a)
+        fb, self._zi_b = sosfilt(self._sos_b, chunk.samples, zi=self._zi_b)
+
+        window_n = int(self._window_s * chunk.sample_rate)
+        self._buf_a = np.concatenate([self._buf_a, fa])[-window_n:]
+        self._buf_b = np.concatenate([self._buf_b, fb])[-window_n:]
+
+        if self._buf_a.shape[0] < window_n:
+            result.detections[self.id] = {"active": False, "best_lag_ms": None, "max_corr": None}
+            return result
+
+        a = self._buf_a - np.mean(self._buf_a)
+        b = self._buf_b - np.mean(self._buf_b)
+        norm = np.sqrt(np.sum(a ** 2) * np.sum(b ** 2))
+
+        # correlate(b, a) peaks at +d when b[n] = a[n - d]
+        corr = correlate(b, a, mode="full", method="fft")
+        lags = correlation_lags(b.shape[0], a.shape[0], mode="full")
+        max_lag_n = int(self._max_lag_s * chunk.sample_rate)
+        in_range = np.abs(lags) <= max_lag_n
+        corr, lags = corr[in_range], lags[in_range]
+
+        best = int(np.argmax(corr))
+        result.detections[self.id] = {
+            "active": False,
+            "best_lag_ms": float(lags[best]) / chunk.sample_rate * 1000,
+            "max_corr": float(corr[best] / norm) if norm > 0 else 0.0,
+        }
+        return result
+
+    def reset(self) -> None:
+        self._sos_a = None
+        self._sos_b = None
+        self._zi_a = None
+        self._zi_b = None
+        self._built_for_rate = 0.0
+        self._buf_a = np.zeros(0)
+        self._buf_b = np.zeros(0)
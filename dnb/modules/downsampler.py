@@ -19,6 +19,8 @@ logger = logging.getLogger(__name__)
 
 
 class Downsampler(Module):
+    transforms_chunk = True
+
     def __init__(self, target_rate: float = 500.0) -> None:
         self._target_rate = target_rate
         self._factor: int = 1
@@ -32,6 +34,9 @@ class Downsampler(Module):
     def actual_rate(self) -> float:
         return self._actual_rate
 
+    def describe(self) -> str:
+        return f"Downsampler: → {self._target_rate:g} Hz"
+
     def configure(self, config: PipelineConfig) -> None:
         self._factor = max(1, int(round(config.sample_rate / self._target_rate)))
         self._actual_rate = config.sample_rate / self._factor
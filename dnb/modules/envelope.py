@@ -0,0 +1,156 @@
+"""Envelope detector — instantaneous amplitude of one band, single channel.
+
+The chunk is bandpassed (causal, filter state carried across chunks) and
+the envelope is estimated per sample as sqrt(2 · lowpass(x²)). For a
+narrowband signal this equals the Hilbert (analytic-signal) amplitude,
+smoothed to `smoothing_hz`, and unlike a Hilbert transform it needs no
+future samples. The envelope is z-scored against a Welford baseline of
+past envelope samples (active chunks excluded, like AmplitudeMonitor).
+
+Reports into result.detections[id]:
+    envelope   — envelope at the latest sample
+    envelope_z — its z-score (None during warmup)
+    active     — envelope_z > z_threshold
+"""
+
+from __future__ import annotations
+
+import logging
+
+import numpy as np
+from numpy.typing import NDArray
+from scipy.signal import butter, sosfilt
+
+from dnb.core.stats import RollingStats
+from dnb.core.types import PipelineConfig
+from dnb.modules.base import Module, ProcessResult, describe_band
+
+logger = logging.getLogger(__name__)
+
+
+class EnvelopeDetector(Module):
+    """Band envelope with a z-score threshold.
+
+    Args:
+        id: Detector identifier (key in result.detections).
+        freq_range: (lo, hi) Hz of the bandpass.
+        z_threshold: Envelope z-score above which the detector is active.
+        smoothing_hz: Lowpass cutoff of the envelope. Defaults to the low
+            band edge, which removes the 2·f ripple of the squared signal.
+        warmup_chunks: Chunks used only to build the baseline.
+        filter_order: Butterworth order of the bandpass.
+    """
+
+    _state_attrs = (
+        "_sos_band", "_sos_lp", "_zi_band", "_zi_lp", "_built_for_rate",
+        "_chunks_seen", "_stats",
+    )
+
+    def __init__(
+        self,
+        id: str = "envelope",
+        freq_range: tuple[float, float] = (0.5, 4.0),
+        z_threshold: float = 3.0,
+        smoothing_hz: float | None = None,
+        warmup_chunks: int = 20,
+        filter_order: int = 2,
+        band_name: str | None = None,
+        role: str | None = None,
+    ) -> None:
+        self.id = id
+        self._freq_range = freq_range
+        self._z_threshold = z_threshold
+        self._smoothing_hz = smoothing_hz if smoothing_hz is not None else freq_range[0]
+        self._warmup_chunks = warmup_chunks
+        self._filter_order = filter_order
+        self.band_name = band_name
+        self.role = role
+
+        self._sos_band: np.ndarray | None = None
+        self._sos_lp: np.ndarray | None = None
+        self._zi_band: np.ndarray | None = None
+        self._zi_lp: np.ndarray | None = None
+        self._built_for_rate: float = 0.0
+        self._chunks_seen: int = 0
+        self._stats = RollingStats()
+
+    def describe(self) -> str:
+        return (
+            f"{type(self).__name__} '{self.id}': "
+            f"{describe_band(self._freq_range, self.band_name, self.role)}"
+        )
+
+    def configure(self, config: PipelineConfig) -> None:
+        logger.info(
+            "%s '%s': freq=(%.1f,%.1f), z>%.1f, smoothing=%.1f Hz, warmup=%d",
+            type(self).__name__, self.id, *self._freq_range,
+            self._z_threshold, self._smoothing_hz, self._warmup_chunks,
+        )
+
+    def _build_filters(self, sample_rate: float) -> None:
+        nyq = sample_rate / 2.0
+        lo, hi = self._freq_range
+        if not 0.0 < lo < hi < nyq or not 0.0 < self._smoothing_hz < nyq:
+            logger.warning(
+                "%s '%s': band (%.1f,%.1f) invalid at %.0f Hz — disabling",
+                type(self).__name__, self.id, lo, hi, sample_rate,
+            )
+            self._sos_band = self._sos_lp = None
+            return
+        self._sos_band = butter(self._filter_order, self._freq_range, btype="band",
+                                output="sos", fs=sample_rate)
+        self._sos_lp = butter(2, self._smoothing_hz, btype="low", output="sos", fs=sample_rate)
+        self._zi_band = np.zeros((self._sos_band.shape[0], 2))
+        self._zi_lp = np.zeros((self._sos_lp.shape[0], 2))
+        self._built_for_rate = sample_rate
+
+    def process(self, result: ProcessResult) -> ProcessResult:
+        chunk = result.chunk
+        if self._sos_band is None or abs(chunk.sample_rate - self._built_for_rate) > 0.1:
+            self._build_filters(chunk.sample_rate)
+        if self._sos_band is None or chunk.n_samples == 0:
+            result.detections[self.id] = {"active": False, "envelope": None, "envelope_z": None}
+            return result
+
+        filtered, self._zi_band = sosfilt(self._sos_band, chunk.samples, zi=self._zi_band)
+        power, self._zi_lp = sosfilt(self._sos_lp, filtered ** 2, zi=self._zi_lp)
+        envelope = np.sqrt(np.maximum(2.0 * power, 0.0))
+        self._chunks_seen += 1
+
+        if self._chunks_seen <= self._warmup_chunks:
+            self._stats.update_batch(envelope)
+            result.detections[self.id] = {
+                "active": False, "envelope": float(envelope[-1]),
+                "envelope_z": None, "warming_up": True,
+            }
+            return result
+
+        std = self._stats.std
+        z = (envelope - self._stats.mean) / std if std > 0 else np.zeros_like(envelope)
+        if not self._detect(result, envelope, z):
+            self._stats.update_batch(envelope)
+        return result
+
+    def _detect(
+        self, result: ProcessResult,
+        envelope: NDArray[np.float64], z: NDArray[np.float64],
+    ) -> bool:
+        """Write result.detections[id]; return True to keep the chunk out
+        of the baseline (it contains above-threshold envelope).
+        """
+        active = bool(z[-1] > self._z_threshold)
+        result.detections[self.id] = {
+            "active": active,
+            "envelope": float(envelope[-1]),
+            "envelope_z": float(z[-1]),
+        }
+        return active
+
+    def reset(self) -> None:
+        self._sos_band = None
+        self._sos_lp = None
+        self._zi_band = None
+        self._zi_lp = None
+        self._built_for_rate = 0.0
+        self._chunks_seen = 0
+        self._stats = RollingStats()
@@ -0,0 +1,410 @@
+"""Chunk filters — causal IIR stages that run before the ring buffer.
+
+Each filter rewrites result.chunk with the filtered signal (the source
+chunk stays available as result.raw_chunk). Filter state is carried
+across chunks, so output is identical to filtering the whole recording
+in one pass. Coefficients are designed on the first chunk for the rate
+the filter actually sees, so a filter placed after the Downsampler runs
+at the analysis rate.
+
+MovingAverageFilter is the one FIR stage: a boxcar average over a
+window carried across chunks the same way.
+
+filter_offline() is the zero-phase (forward-backward) counterpart for
+whole recordings. It is non-causal — for offline analysis and
+validation only, never in closed-loop mode.
+"""
+
+from __future__ import annotations
+
+import logging
+
+import numpy as np
+from numpy.typing import NDArray
+from scipy.signal import group_delay, iirnotch, sosfilt, sosfiltfilt, tf2sos
+
+from dnb.core.types import DataChunk, PipelineConfig
+from dnb.errors import ConfigError
+from dnb.modules.base import Module, ProcessResult
+
+logger = logging.getLogger(__name__)
+
+
+class SosFilter(Module):
+    """Base for stateful second-order-section chunk filters.
+
+    Subclasses implement _design(sample_rate) returning an (n, 6) SOS
+    array, or None to pass the signal through unchanged.
+    """
+
+    transforms_chunk = True
+    _state_attrs = ("_sos", "_zi", "_built_for_rate")
+
+    def __init__(self, id: str) -> None:
+        self.id = id
+        self._sos: np.ndarray | None = None
+        self._zi: np.ndarray | None = None
+        self._built_for_rate: float = 0.0
+
+    def _design(self, sample_rate: float) -> np.ndarray | None:
+        raise NotImplementedError
+
+    @property
+    def sos(self) -> np.ndarray | None:
+        """Coefficients in use, (n_sections, 6) as [b0 b1 b2 a0 a1 a2] rows.
+
+        None until the first chunk (or when the filter passes through).
+        """
+        return None if self._sos is None else self._sos.copy()
+
+    def coefficients(self, sample_rate: float) -> np.ndarray | None:
+        """The SOS this filter designs at `sample_rate`, without running it.
+
+        Each row is one biquad, normalized so a0 == 1 — compare with
+        MATLAB's [sos, g] = tf2sos(b, a) after folding g into the first row.
+        """
+        return self._design(sample_rate)
+
+    def group_delay(self, freq: float, sample_rate: float) -> float:
+        """Delay in samples this filter adds at `freq` (Hz), at `sample_rate`.
+
+        Sum of the sections' group delays. Subtract it from indices found
+        on the filtered signal to align them with the raw one. Near a
+        notch the delay is large and can be negative; it is only
+        meaningful for frequencies the filter passes.
+        """
+        sos = self._design(sample_rate)
+        if sos is None:
+            return 0.0
+        total = 0.0
+        for section in sos:
+            _, gd = group_delay((section[:3], section[3:]), w=[freq], fs=sample_rate)
+            total += float(gd[0])
+        return total
+
+    def configure(self, config: PipelineConfig) -> None:
+        self.reset()
+
+    def filter_offline(
+        self, samples: NDArray[np.float64], sample_rate: float,
+    ) -> NDArray[np.float64]:
+        """Zero-phase filter a whole 1D signal (forward then backward).
+
+        Non-causal: uses future samples, so there is no group delay but
+        it cannot run live. Independent of the streaming state.
+        """
+        sos = self._design(sample_rate)
+        if sos is None:
+            return np.asarray(samples, dtype=np.float64).copy()
+        return sosfiltfilt(sos, samples)
+
+    def _build(self, sample_rate: float) -> None:
+        self._sos = self._design(sample_rate)
+        self._zi = None if self._sos is None else np.zeros((self._sos.shape[0], 2))
+        self._built_for_rate = sample_rate
+
+    def process(self, result: ProcessResult) -> ProcessResult:
+        chunk = result.chunk
+        if chunk.n_samples == 0:
+            return result
+        if abs(chunk.sample_rate - self._built_for_rate) > 0.1:
+            self._build(chunk.sample_rate)
+        if self._sos is None:
+            return result
+
+        filtered, self._zi = sosfilt(self._sos, chunk.samples, zi=self._zi)
+        result.chunk = DataChunk(
+            samples=filtered,
+            timestamps=chunk.timestamps,
+            channel_id=chunk.channel_id,
+            sample_rate=chunk.sample_rate,
+        )
+        return result
+
+    def reset(self) -> None:
+        self._sos = None
+        self._zi = None
+        self._built_for_rate = 0.0
+
+
+def filter_offline(
+    samples: NDArray[np.float64],
+    sample_rate: float,
+    filters: list[SosFilter | MovingAverageFilter],
+) -> NDArray[np.float64]:
+    """Apply a filter chain zero-phase to a whole recording, in order.
+
+    For offline timing validation: detected indices are not shifted by
+    filter group delay. NOT for closed-loop use — see module docstring.
+    """
+    out = np.asarray(samples, dtype=np.float64)
+    for f in filters:
+        out = f.filter_offline(out, sample_rate)
+    return out
+
+
+class CombNotchFilter(SosFilter):
+    """Notches line noise and its harmonics.
+
+    One biquad notch per harmonic (base_freq, 2·base_freq, ...), cascaded.
+    Harmonics at or above Nyquist are skipped.
+
+    Args:
+        id: Filter identifier.
+        base_freq: Line frequency in Hz (50 or 60).
+        n_harmonics: Number of frequencies notched, including base_freq.
+        q: Quality factor of each notch (higher = narrower).
+    """
+
+    def __init__(
+        self,
+        id: str = "line_noise",
+        base_freq: float = 50.0,
+        n_harmonics: int = 3,
+        q: float = 30.0,
+    ) -> None:
+        super().__init__(id)
+        if base_freq <= 0 or n_harmonics < 1 or q <= 0:
+            raise ConfigError(
+                f"CombNotchFilter '{id}': need base_freq > 0, n_harmonics >= 1, q > 0"
+            )
+        self._base_freq = base_freq
+        self._n_harmonics = n_harmonics
+        self._q = q
+
+    def describe(self) -> str:
+        return f"CombNotchFilter '{self.id}': {self._base_freq:g} Hz × {self._n_harmonics}"
+
+    def configure(self, config: PipelineConfig) -> None:
+        super().configure(config)
+        logger.info(
+            "CombNotchFilter '%s': %.0f Hz + harmonics up to ×%d, Q=%.0f",
+            self.id, self._base_freq, self._n_harmonics, self._q,
+        )
+
+    def _design(self, sample_rate: float) -> np.ndarray | None:
+        nyq = sample_rate / 2.0
+        sections = []
+        for k in range(1, self._n_harmonics + 1):
+            f0 = k * self._base_freq
+            if f0 >= nyq:
+                logger.info(
+                    "CombNotchFilter '%s': %.0f Hz ≥ Nyquist at %.0f Hz — skipped",
+                    self.id, f0, sample_rate,
+                )
+                break
+            b, a = iirnotch(f0, self._q, fs=sample_rate)
+            sections.append(tf2sos(b, a))
+        if not sections:
+            return None
+        return np.vstack(sections)
+
+
+class NotchFilter(SosFilter):
+    """Single biquad band-stop at `freq` (e.g. 50 or 60 Hz mains).
+
+    Args:
+        id: Filter identifier.
+        freq: Centre frequency in Hz.
+        q: Quality factor (higher = narrower notch).
+    """
+
+    def __init__(self, id: str = "notch", freq: float = 50.0, q: float = 30.0) -> None:
+        super().__init__(id)
+        if freq <= 0 or q <= 0:
+            raise ConfigError(f"NotchFilter '{id}': need freq > 0 and q > 0")
+        self._freq = freq
+        self._q = q
+
+    def describe(self) -> str:
+        return f"NotchFilter '{self.id}': {self._freq:g} Hz, Q={self._q:g}"
+
+    def configure(self, config: PipelineConfig) -> None:
+        super().configure(config)
+        logger.info("NotchFilter '%s': %.1f Hz, Q=%.0f", self.id, self._freq, self._q)
+
+    def _design(self, sample_rate: float) -> np.ndarray | None:
+        if self._freq >= sample_rate / 2.0:
+            logger.warning(
+                "NotchFilter '%s': %.0f Hz ≥ Nyquist at %.0f Hz — passing through",
+                self.id, self._freq, sample_rate,
+            )
+            return None
+        b, a = iirnotch(self._freq, self._q, fs=sample_rate)
+        return tf2sos(b, a)
+
+
+class BaselineRemovalFilter(SosFilter):
+    """One-pole high-pass (DC blocker) that removes slow electrode drift.
+
+    y[n] = g · (x[n] − x[n−1]) + r · y[n−1], with r = exp(−2π·cutoff/fs)
+    and g = (1 + r) / 2 for unit gain at Nyquist. A drifting baseline
+    shifts zero crossings that the wavelet and T-wave stages rely on;
+    this is a cheap first stage to take it out before they see it.
+
+    Args:
+        id: Filter identifier.
+        cutoff_hz: −3 dB frequency in Hz — keep well below the band of
+            interest (default 0.1 Hz, under slow-wave frequencies).
+    """
+
+    def __init__(self, id: str = "baseline_removal", cutoff_hz: float = 0.1) -> None:
+        super().__init__(id)
+        if cutoff_hz <= 0:
+            raise ConfigError(f"BaselineRemovalFilter '{id}': cutoff_hz must be > 0")
+        self._cutoff_hz = cutoff_hz
+
+    def describe(self) -> str:
+        return f"BaselineRemovalFilter '{self.id}': {self._cutoff_hz:g} Hz"
+
+    def configure(self, config: PipelineConfig) -> None:
+        super().configure(config)
+        logger.info("BaselineRemovalFilter '%s': cutoff %.2f Hz", self.id, self._cutoff_hz)
+
+    def _design(self, sample_rate: float) -> np.ndarray | None:
+        if self._cutoff_hz >= sample_rate / 2.0:
+            logger.warning(
+                "BaselineRemovalFilter '%s': %.1f Hz ≥ Nyquist at %.0f Hz — passing through",
+                self.id, self._cutoff_hz, sample_rate,
+            )
+            return None
+        r = float(np.exp(-2.0 * np.pi * self._cutoff_hz / sample_rate))
+        g = (1.0 + r) / 2.0
+        return np.array([[g, -g, 0.0, 1.0, -r, 0.0]])
+
+
+class IirFilter(SosFilter):
+    """Arbitrary-order IIR filter from transfer-function coefficients.
+
+    For filters designed elsewhere (e.g. MATLAB's [b, a] = butter(...)):
+    b and a are used exactly, converted to second-order sections for
+    numerical stability. Coefficients only mean something at the rate
+    they were designed for, so give that rate as design_rate; at any
+    other rate the filter passes the signal through with a warning.
+
+    Args:
+        id: Filter identifier.
+        b: Numerator coefficients.
+        a: Denominator coefficients (a[0] != 0; normalized internally).
+        design_rate: Sample rate in Hz the coefficients were designed for.
+    """
+
+    def __init__(
+        self,
+        id: str = "iir",
+        *,
+        b: list[float],
+        a: list[float],
+        design_rate: float,
+    ) -> None:
+        super().__init__(id)
+        self._b = np.asarray(b, dtype=np.float64)
+        self._a = np.asarray(a, dtype=np.float64)
+        if self._b.ndim != 1 or self._a.ndim != 1 or not self._b.size or not self._a.size:
+            raise ConfigError(f"IirFilter '{id}': b and a must be non-empty 1D lists")
+        if self._a[0] == 0:
+            raise ConfigError(f"IirFilter '{id}': a[0] must be non-zero")
+        if design_rate <= 0:
+            raise ConfigError(f"IirFilter '{id}': design_rate must be > 0")
+        self._design_rate = design_rate
+
+    def describe(self) -> str:
+        order = max(self._b.size, self._a.size) - 1
+        return f"IirFilter '{self.id}': order {order} @ {self._design_rate:g} Hz"
+
+    def configure(self, config: PipelineConfig) -> None:
+        super().configure(config)
+        logger.info(
+            "IirFilter '%s': %d b / %d a coefficients, designed at %.0f Hz",
+            self.id, self._b.size, self._a.size, self._design_rate,
+        )
+
+    def _design(self, sample_rate: float) -> np.ndarray | None:
+        if abs(sample_rate - self._design_rate) > 0.1:
+            logger.warning(
+                "IirFilter '%s': designed for %.0f Hz but running at %.0f Hz — passing through",
+                self.id, self._design_rate, sample_rate,
+            )
+            return None
+        return tf2sos(self._b, self._a)
+
+
+class MovingAverageFilter(Module):
+    """Causal moving average (boxcar) over the last `window_ms`.
+
+    Smooths, e.g. for a baseline estimate, where a biquad's ringing is
+    unwanted. The window spans chunk boundaries; before the first full
+    window the signal is taken as constant at its first sample, so there
+    is no start-up dip. Each chunk is a cumulative sum over the previous
+    window tail plus the chunk, so rounding error can't build up over a
+    session.
+
+    Args:
+        id: Filter identifier.
+        window_ms: Averaging window in ms (rounded to whole samples).
+    """
+
+    transforms_chunk = True
+    _state_attrs = ("_n", "_tail", "_built_for_rate")
+
+    def __init__(self, id: str = "moving_average", window_ms: float = 20.0) -> None:
+        if window_ms <= 0:
+            raise ConfigError(f"MovingAverageFilter '{id}': window_ms must be > 0")
+        self.id = id
+        self._window_ms = window_ms
+        self._n = 1
+        self._tail: np.ndarray | None = None
+        self._built_for_rate: float = 0.0
+
+    def window_samples(self, sample_rate: float) -> int:
+        return max(1, int(round(self._window_ms * sample_rate / 1000.0)))
+
+    def group_delay(self, freq: float, sample_rate: float) -> float:
+        """Delay in samples — (N − 1) / 2 at every frequency (linear phase)."""
+        return (self.window_samples(sample_rate) - 1) / 2.0
+
+    def describe(self) -> str:
+        return f"MovingAverageFilter '{self.id}': {self._window_ms:g} ms"
+
+    def configure(self, config: PipelineConfig) -> None:
+        self.reset()
+        logger.info("MovingAverageFilter '%s': %.1f ms window", self.id, self._window_ms)
+
+    def filter_offline(
+        self, samples: NDArray[np.float64], sample_rate: float,
+    ) -> NDArray[np.float64]:
+        """Centred moving average of a whole 1D signal — no delay, non-causal."""
+        x = np.asarray(samples, dtype=np.float64)
+        n = self.window_samples(sample_rate)
+        padded = np.pad(x, ((n - 1) // 2, n // 2), mode="edge")
+        return np.convolve(padded, np.full(n, 1.0 / n), mode="valid")
+
+    def process(self, result: ProcessResult) -> ProcessResult:
+        chunk = result.chunk
+        if chunk.n_samples == 0:
+            return result
+        if abs(chunk.sample_rate - self._built_for_rate) > 0.1:
+            self._n = self.window_samples(chunk.sample_rate)
+            self._tail = None
+            self._built_for_rate = chunk.sample_rate
+        n = self._n
+        if self._tail is None:
+            self._tail = np.full(n - 1, chunk.samples[0])
+
+        x = np.concatenate([self._tail, chunk.samples])
+        csum = np.concatenate([[0.0], np.cumsum(x)])
+        averaged = (csum[n:] - csum[:-n]) / n
+        self._tail = x[x.shape[0] - (n - 1):]
+
+        result.chunk = DataChunk(
+            samples=averaged,
+            timestamps=chunk.timestamps,
+            channel_id=chunk.channel_id,
+            sample_rate=chunk.sample_rate,
+        )
+        return result
+
+    def reset(self) -> None:
+        self._n = 1
+        self._tail = None
+        self._built_for_rate = 0.0
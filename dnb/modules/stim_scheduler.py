@@ -1,7 +1,9 @@
 """Threaded stim scheduler for live closed-loop operation.
 
 Receives STIM events with exact predicted timestamps, sleeps until
-each stim time using perf_counter, fires audio.
+each stim time, fires audio. Time comes from `clock` — perf_counter by
+default (monotonic, unaffected by system clock steps); pass a fake clock
+for deterministic tests, or time.time to schedule in wall-clock time.
 """
 
 from __future__ import annotations
@@ -26,8 +28,10 @@ class StimScheduler:
         wav_path: str | Path | None = None,
         volume: float = 1.0,
         on_fire: Callable[[Event, float, float], None] | None = None,
+        clock: Callable[[], float] = time.perf_counter,
     ) -> None:
         self._wav_path = Path(wav_path) if wav_path else None
+        self._clock = clock
         self._volume = max(0.0, min(1.0, volume))
         self._on_fire = on_fire
 
@@ -43,6 +47,8 @@ class StimScheduler:
         self._running = False
         self._stim_count = 0
         self._time_offset: float | None = None
+        self._stim_enabled = threading.Event()
+        self._stim_enabled.set()
 
         self._load_audio()
 
@@ -68,6 +74,10 @@ class StimScheduler:
         except ImportError:
             self._sa_available = False
 
+    def now(self) -> float:
+        """Current time on the scheduler's clock (use for set_time_offset)."""
+        return self._clock()
+
     def set_time_offset(self, pipeline_time: float, real_time: float) -> None:
         self._time_offset = real_time - pipeline_time
 
@@ -76,8 +86,25 @@ class StimScheduler:
             return pipeline_time
         return pipeline_time + self._time_offset
 
+    @property
+    def stimulation_enabled(self) -> bool:
+        return self._stim_enabled.is_set()
+
+    def set_stimulation_enabled(self, enabled: bool) -> None:
+        """Kill switch. Disabling drops every pending stim immediately."""
+        if enabled:
+            self._stim_enabled.set()
+        else:
+            self._stim_enabled.clear()
+            with self._lock:
+                dropped = len(self._pending)
+                self._pending.clear()
+            logger.warning("StimScheduler: stimulation DISABLED (%d pending dropped)", dropped)
+            return
+        logger.warning("StimScheduler: stimulation ENABLED")
+
     def on_stim_event(self, event: Event) -> None:
-        if event.event_type != EventType.STIM:
+        if event.event_type != EventType.STIM or not self._stim_enabled.is_set():
             return
         real_time = self._to_real(event.timestamp)
         with self._lock:
@@ -102,19 +129,20 @@ class StimScheduler:
             with self._lock:
                 if self._pending:
                     next_time, next_event = self._pending[0]
-                    if time.perf_counter() >= next_time - 0.001:
+                    if self._clock() >= next_time - 0.001:
                         event_to_fire = self._pending.pop(0)
 
             if event_to_fire is not None:
                 target_time, event = event_to_fire
-                while time.perf_counter() < target_time:
+                while self._clock() < target_time:
                     pass
-                self._fire(event, target_time)
+                if self._stim_enabled.is_set():
+                    self._fire(event, target_time)
             else:
                 time.sleep(0.0005)
 
     def _fire(self, event: Event, target_time: float) -> None:
-        actual_time = time.perf_counter()
+        actual_time = self._clock()
         jitter_ms = (actual_time - target_time) * 1000
         self._stim_count += 1
 
@@ -135,4 +163,20 @@ class StimScheduler:
 
     @property
     def stim_count(self) -> int:
-        return self._stim_count
\ No newline at end of file
+        return self._stim_count
+
+    @property
+    def pending_count(self) -> int:
+        with self._lock:
+            return len(self._pending)
+
+    def time_until_next_stim(self) -> float:
+        """Seconds until the next scheduled stim fires, or -1.0 if none pending.
+
+        For live displays. Counts down to 0 at the fire time.
+        """
+        with self._lock:
+            if not self._pending:
+                return -1.0
+            next_time = self._pending[0][0]
+        return max(0.0, next_time - self._clock())
\ No newline at end of file
@@ -7,50 +7,187 @@ so this module doesn't need to compute phase delays.
 It still handles:
     - Backoff (minimum gap between stim sequences)
     - Inhibition (from AmplitudeMonitor or similar)
-    - Inhibition cooldown
-    - N-pulse scheduling (multiple stims at successive predicted peaks)
+    - Inhibition cooldown (inhibition channel must be quiet for
+      inhibition_cooldown_s before a detection is accepted)
+    - N-pulse scheduling (multiple stims at successive predicted peaks,
+      or a fixed-rate train with inter_pulse_interval_s)
+    - Global kill switch (set_stimulation_enabled) — detections continue,
+      STIM events stop
 """
 
 from __future__ import annotations
 
 import logging
+import threading
 from math import pi
 
 import numpy as np
 
 from dnb.core.types import Event, EventType, PipelineConfig
+from dnb.errors import ConfigError
 from dnb.modules.base import Module, ProcessResult
 
 logger = logging.getLogger(__name__)
 
 
 class StimTrigger(Module):
+    """Turns activation candidates into SLOW_WAVE + STIM events.
+
+    `activation_detector_id` may be a list of ids combined with
+    `activation_mode`: "all" needs every listed detector active in the
+    same chunk, "any" needs at least one. Stim timing comes from
+    `timing_detector_id` if set (one of the listed ids), otherwise from
+    the first listed detector that offers phase-predicted candidates, so
+    at least one of them should be a TWaveDetector.
+
+    With `min_activation_confidence` set, a candidate whose
+    `confidence_key` value (e.g. "template_score", "amplitude") is below
+    it, or missing, is dropped and counted as "low_confidence".
+    """
+
     def __init__(
         self,
-        activation_detector_id: str = "slow_wave",
+        id: str = "trigger",
+        activation_detector_id: str | list[str] = "slow_wave",
         inhibition_detector_id: str | None = "ied_monitor",
         n_pulses: int = 1,
         backoff_s: float = 5.0,
         inhibition_cooldown_s: float = 5.0,
+        inter_pulse_interval_s: float | None = None,
+        stim_delay_s: float = 0.0,
+        activation_mode: str = "all",
+        min_activation_confidence: float | None = None,
+        confidence_key: str = "template_score",
+        timing_detector_id: str | None = None,
     ) -> None:
-        self._act_id = activation_detector_id
+        ids = (
+            [activation_detector_id] if isinstance(activation_detector_id, str)
+            else list(activation_detector_id)
+        )
+        if not ids:
+            raise ConfigError("StimTrigger: activation_detector_id list is empty")
+        if activation_mode not in ("all", "any"):
+            raise ConfigError(f"activation_mode must be 'all' or 'any', got {activation_mode!r}")
+        if timing_detector_id is not None and timing_detector_id not in ids:
+            raise ConfigError(
+                f"timing_detector_id {timing_detector_id!r} is not an activation detector "
+                f"({', '.join(ids)})"
+            )
+        self.id = id
+        self._act_ids = ids
+        self._act_mode = activation_mode
+        self._timing_id = timing_detector_id
+        # Single id as-is; combinations read as "a&b" (all) or "a|b" (any)
+        self._act_id = ids[0] if len(ids) == 1 else (
+            "&" if activation_mode == "all" else "|"
+        ).join(ids)
         self._inh_id = inhibition_detector_id
         self._n_pulses = n_pulses
         self._backoff_s = backoff_s
         self._inhibition_cooldown_s = inhibition_cooldown_s
+        if inter_pulse_interval_s is not None and inter_pulse_interval_s <= 0:
+            raise ConfigError(f"inter_pulse_interval_s must be > 0, got {inter_pulse_interval_s}")
+        self._inter_pulse_interval_s = inter_pulse_interval_s
+        # Fixed offset added to every predicted stim time, e.g. to cancel
+        # stimulator hardware latency (negative = fire earlier)
+        self._stim_delay_s = stim_delay_s
+        self._min_confidence = min_activation_confidence
+        self._confidence_key = confidence_key
 
         self._last_detection_time: float = -np.inf
         self._last_inhibition_time: float = -np.inf
+        self._stats = self._empty_stats()
+        self._warned_missing: set[str] = set()
+
+        # Kill switch — an Event so it can be flipped from another thread
+        self._stim_enabled = threading.Event()
+        self._stim_enabled.set()
+
+    @property
+    def activation_id(self) -> str:
+        return self._act_id
+
+    @property
+    def stimulation_enabled(self) -> bool:
+        return self._stim_enabled.is_set()
+
+    def set_stimulation_enabled(self, enabled: bool) -> None:
+        """Allow or block STIM events. SLOW_WAVE detections are unaffected."""
+        if enabled:
+            self._stim_enabled.set()
+        else:
+            self._stim_enabled.clear()
+        logger.warning("StimTrigger: stimulation %s", "ENABLED" if enabled else "DISABLED")
+
+    @staticmethod
+    def _empty_stats() -> dict[str, int]:
+        return {
+            "detections": 0,   # accepted detections (SLOW_WAVE events)
+            "stims": 0,        # STIM pulses emitted
+            "suppressed": 0,   # pulses withheld by the kill switch
+            "inhibited": 0,    # candidates dropped by inhibition / cooldown
+            "backoff": 0,      # candidates dropped by backoff
+            "low_confidence": 0,  # candidates below min_activation_confidence
+        }
+
+    def trigger_stats(self) -> dict[str, int]:
+        """Counters since configure(), for live display.
+
+        Kept across the end-of-run reset() so they can be read afterwards.
+        """
+        return dict(self._stats)
+
+    def describe(self) -> str:
+        return (
+            f"StimTrigger '{self.id}': act='{self._act_id}', inh='{self._inh_id or 'none'}', "
+            f"n_pulses={self._n_pulses}"
+        )
 
     def configure(self, config: PipelineConfig) -> None:
+        self._stats = self._empty_stats()
+        self._warned_missing = set()
         logger.info(
-            "StimTrigger: act='%s', inh='%s', n_pulses=%d, backoff=%.1fs",
-            self._act_id, self._inh_id or "none",
-            self._n_pulses, self._backoff_s,
+            "StimTrigger: act='%s', inh='%s', n_pulses=%d, spacing=%s, "
+            "delay=%+.0f ms, backoff=%.1fs",
+            self._act_id, self._inh_id or "none", self._n_pulses,
+            f"{self._inter_pulse_interval_s * 1000:.0f} ms"
+            if self._inter_pulse_interval_s else "1 period",
+            self._stim_delay_s * 1000, self._backoff_s,
         )
 
+    def _warn_if_missing(self, result: ProcessResult, det_id: str | None, role: str) -> None:
+        """Warn once per id when a referenced detector writes no output.
+
+        Usually a typo, a disabled detector or a module-order problem; the
+        trigger carries on treating that detector as inactive.
+        """
+        if det_id is None or det_id in result.detections or det_id in self._warned_missing:
+            return
+        self._warned_missing.add(det_id)
+        logger.warning(
+            "StimTrigger: %s detector '%s' produced no output (known: %s) — treating as inactive",
+            role, det_id, ", ".join(sorted(result.detections)) or "none",
+        )
+
+    def _activation_candidates(self, result: ProcessResult) -> list[dict]:
+        """Candidates of this chunk if the activation logic is satisfied, else []."""
+        dets = [result.detections.get(i, {}) for i in self._act_ids]
+        flags = [d.get("active", False) for d in dets]
+        if not (all(flags) if self._act_mode == "all" else any(flags)):
+            return []
+        if self._timing_id is not None:
+            timing = result.detections.get(self._timing_id, {})
+            return (timing.get("candidates") or []) if timing.get("active", False) else []
+        for d in dets:
+            if d.get("candidates"):
+                return d["candidates"]
+        return []
+
     def process(self, result: ProcessResult) -> ProcessResult:
-        activation = result.detections.get(self._act_id, {})
+        for det_id in self._act_ids:
+            self._warn_if_missing(result, det_id, "activation")
+        self._warn_if_missing(result, self._inh_id, "inhibition")
+        candidates = self._activation_candidates(result)
         inhibition = result.detections.get(self._inh_id, {}) if self._inh_id else {}
         inhibition_active = inhibition.get("active", False)
 
@@ -61,16 +198,23 @@ class StimTrigger(Module):
         # --- Inhibition ---
         if inhibition_active:
             self._last_inhibition_time = chunk_time
+            if candidates:
+                self._stats["inhibited"] += 1
             result.events.extend(events)
             return result
 
         # --- Process candidates ---
-        candidates = activation.get("candidates", [])
         if not candidates:
             result.events.extend(events)
             return result
 
         c = candidates[0]
+        if self._min_confidence is not None:
+            score = c.get(self._confidence_key)
+            if score is None or score < self._min_confidence:
+                self._stats["low_confidence"] += 1
+                result.events.extend(events)
+                return result
         t_stim = c["timestamp"]       # already the predicted stim time
         freq = c["frequency"]
         amplitude = c["amplitude"]
@@ -78,43 +222,68 @@ class StimTrigger(Module):
 
         # Backoff check (based on current time, not predicted time)
         if t_now - self._last_detection_time < self._backoff_s:
+            self._stats["backoff"] += 1
             result.events.extend(events)
             return result
 
         # Inhibition cooldown check
         if t_now - self._last_inhibition_time < self._inhibition_cooldown_s:
+            self._stats["inhibited"] += 1
             result.events.extend(events)
             return result
 
         self._last_detection_time = t_now
+        self._stats["detections"] += 1
         period = 1.0 / freq if freq > 0 else 1.0
+        # Pulse spacing: one wave period (successive peaks) or a fixed train rate
+        spacing = self._inter_pulse_interval_s or period
+        # A negative delay can't move the stim into the past
+        clamped = t_stim + self._stim_delay_s < t_now
+        t_stim = max(t_stim + self._stim_delay_s, t_now)
 
         # Emit SLOW_WAVE event (detection happened now, stim is predicted)
+        metadata = {
+            "frequency": freq,
+            "period_ms": period * 1000,
+            "amplitude": amplitude,
+            "phase_now": c.get("phase_now", 0.0),
+            "dt_to_stim_ms": (t_stim - t_now) * 1000,
+            "stim_clamped": clamped,
+            "n_pulses": self._n_pulses,
+            "stimulation_enabled": self.stimulation_enabled,
+            "activation_id": self._act_id,
+            "trigger_id": self.id,
+        }
+        for key in ("downslope", "upslope", "symmetry", "waveform"):
+            if key in c:
+                metadata[key] = c[key]
         events.append(Event(
             event_type=EventType.SLOW_WAVE,
             timestamp=t_now,
             channel_id=ch_id,
-            metadata={
-                "frequency": freq,
-                "amplitude": amplitude,
-                "phase_now": c.get("phase_now", 0.0),
-                "dt_to_stim_ms": c.get("dt_to_target_ms", 0.0),
-                "n_pulses": self._n_pulses,
-            },
+            metadata=metadata,
         ))
 
         # Emit stim events with exact predicted timestamps
-        if self._n_pulses > 0 and freq > 0:
+        if self._n_pulses > 0 and freq > 0 and not self.stimulation_enabled:
+            self._stats["suppressed"] += self._n_pulses
+        elif self._n_pulses > 0 and freq > 0:
+            self._stats["stims"] += self._n_pulses
             for k in range(self._n_pulses):
                 events.append(Event(
                     event_type=EventType.STIM,
-                    timestamp=t_stim + k * period,
+                    timestamp=t_stim + k * spacing,
                     channel_id=ch_id,
                     metadata={
                         "pulse_index": k + 1,
                         "n_pulses": self._n_pulses,
                         "frequency": freq,
+                        "amplitude": amplitude,
                         "detection_time": t_now,
+                        "stim_delay_s": self._stim_delay_s,
+                        "stim_clamped": clamped,
+                        "activation_id": self._act_id,
+                        "trigger_id": self.id,
                     },
                 ))
 
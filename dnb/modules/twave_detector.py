@@ -15,6 +15,9 @@ Multi-feature validation (from TWave paper, Li et al. 2025):
     - High-to-low frequency ratio < threshold (rejects IEDs/artifacts)
     - Template match: dot product of recent signal vs ideal sinusoid
 
+Accepted candidates also carry the morphology of the most recent
+negative half-wave (downslope, upslope, symmetry — see wave_morphology).
+
 This eliminates: phase tolerance parameters, crossing detection,
 wrap-artifact rejection, chunk-boundary sensitivity. The detection
 is one comparison per chunk, not a scan.
@@ -23,16 +26,57 @@ is one comparison per chunk, not a scan.
 from __future__ import annotations
 
 import logging
-from math import pi
+from collections import deque
+from math import exp, pi
 
 import numpy as np
 from numpy.typing import NDArray
+from scipy.signal import butter, sosfiltfilt
 
 from dnb.core.types import PipelineConfig
-from dnb.modules.base import Module, ProcessResult
+from dnb.errors import ConfigError
+from dnb.modules.base import Module, ProcessResult, describe_band
 
 logger = logging.getLogger(__name__)
 
+# Default amp_min_floor as a fraction of the starting amp_min
+_AMP_MIN_FLOOR_FACTOR = 0.5
+
+
+def wave_morphology(x: NDArray[np.float64], sample_rate: float) -> dict[str, float] | None:
+    """Slope/symmetry of the last complete negative half-wave in x.
+
+    Standard slow-wave definitions (Riedner et al. 2007), with x already
+    band-limited to the slow-wave range:
+        downslope — trough amplitude / time from down zero-crossing to trough (µV/s)
+        upslope   — trough amplitude / time from trough to up zero-crossing (µV/s)
+        symmetry  — (down-crossing → trough) / (down-crossing → up-crossing);
+                    0.5 is symmetric, < 0.5 is a steep descent.
+
+    Returns None if x has no complete negative half-wave.
+    """
+    x = x - np.mean(x)
+    neg = x < 0
+    downs = np.flatnonzero(~neg[:-1] & neg[1:]) + 1  # first negative sample
+    ups = np.flatnonzero(neg[:-1] & ~neg[1:]) + 1    # first non-negative sample
+    for up in ups[::-1]:
+        prior = downs[downs < up]
+        if prior.size:
+            down = int(prior[-1])
+            break
+    else:
+        return None
+
+    trough = down + int(np.argmin(x[down:up]))
+    amplitude = float(-x[trough])
+    t_down = max(trough - down, 1) / sample_rate
+    t_up = max(up - trough, 1) / sample_rate
+    return {
+        "downslope": amplitude / t_down,
+        "upslope": amplitude / t_up,
+        "symmetry": (trough - down) / (up - down),
+    }
+
 
 class TWaveDetector(Module):
     """TWave-style slow oscillation detector.
@@ -50,9 +94,51 @@ class TWaveDetector(Module):
         template_threshold: Min dot-product match against ideal sinusoid
             (TWave: 0.8). Set None to disable.
         template_window_s: Seconds of signal history for template matching.
+        template_metric: How the recent signal is scored against the ideal
+            sinusoid (higher = better match, compared to template_threshold):
+            "dot" — dot product of the z-scored signal with a unit cosine
+            (TWave; scale-invariant). "cosine" — cosine similarity of the
+            mean-removed signal (scale-invariant, range [-1, 1]). "nrmse" —
+            1 − RMSE / RMS of the sinusoid scaled to the wavelet amplitude,
+            so a flat wave that merely has the right shape scores low.
         warmup_chunks: Chunks to skip before detection (buffer filling).
+        period_smoothing: EMA weight (0–1] of the newest period estimate used
+            for prediction. Steadies the lead time against chunk-to-chunk
+            frequency jitter. None uses the instantaneous estimate.
+        record_candidates: Log every candidate that reaches validation
+            (target phase within the prediction limit) with all its scores
+            and a ``passed`` flag in ``candidate_log``, for offline
+            threshold sweeps / ROC curves without re-running.
+        candidate_log_length: Most recent candidates kept in
+            ``candidate_log``.
+        debug: Record every chunk's decision state (phase, frequency,
+            amplitude, dt, reject reason) in ``trace`` for tuning plots.
+        trace_length: Most recent chunks kept in ``trace`` (older ones are
+            dropped, so a long session with debug on stays bounded).
+        band_name: Optional label for freq_range (e.g. "slow oscillation").
+        role: Optional role of the band (e.g. "detection").
+        retain_waveform: Keep the raw signal and fitted sinusoid of the last
+            detection (template_window_s long) in ``last_waveform`` and
+            attach it to the candidate for logging.
+        target_rate_per_min: If set, amp_min adapts so detections (onsets
+            of active runs) over the last rate_window_s approach this rate.
+            amp_min is the starting value; None keeps it fixed.
+        rate_window_s: Window over which the detection rate is measured.
+        adapt_gain: Speed of the adaptation, per second: amp_min changes
+            by about adapt_gain × relative rate error each second.
+        amp_min_floor: Lowest value adaptation may take amp_min to, so a
+            quiet stretch can't lower it into the noise. Defaults to half
+            of amp_min.
+        track_prediction_error: After each predicted target, find the
+            actual peak (or trough, whichever the target phase is nearer)
+            within ±¼ period of it on the low-passed signal and report
+            actual − predicted as ``prediction_error_ms`` in the
+            detections of the chunk where it resolves, and in
+            ``prediction_errors``. One prediction is tracked at a time.
     """
 
+    _state_attrs = ("_chunks_seen", "_period_ema", "_amp_min_eff")
+
     def __init__(
         self,
         id: str = "slow_wave",
@@ -65,7 +151,21 @@ class TWaveDetector(Module):
         hilo_boundary_hz: float = 10.0,
         template_threshold: float | None = 0.8,
         template_window_s: float = 2.0,
+        template_metric: str = "dot",
         warmup_chunks: int = 20,
+        retain_waveform: bool = False,
+        period_smoothing: float | None = None,
+        debug: bool = False,
+        trace_length: int = 10_000,
+        record_candidates: bool = False,
+        candidate_log_length: int = 100_000,
+        band_name: str | None = None,
+        role: str | None = None,
+        target_rate_per_min: float | None = None,
+        rate_window_s: float = 60.0,
+        adapt_gain: float = 0.02,
+        amp_min_floor: float | None = None,
+        track_prediction_error: bool = False,
     ) -> None:
         self.id = id
         self._freq_range = freq_range
@@ -77,10 +177,97 @@ class TWaveDetector(Module):
         self._hilo_boundary_hz = hilo_boundary_hz
         self._template_threshold = template_threshold
         self._template_window_s = template_window_s
+        if template_metric not in ("dot", "cosine", "nrmse"):
+            raise ConfigError(
+                f"template_metric must be 'dot', 'cosine' or 'nrmse', got {template_metric!r}"
+            )
+        self._template_metric = template_metric
         self._warmup_chunks = warmup_chunks
+        self._retain_waveform = retain_waveform
+        if period_smoothing is not None and not 0.0 < period_smoothing <= 1.0:
+            raise ConfigError(f"period_smoothing must be in (0, 1], got {period_smoothing}")
+        self._period_smoothing = period_smoothing
+        self._period_ema: float | None = None
+        self._debug = debug
+        if trace_length < 1:
+            raise ConfigError(f"trace_length must be >= 1, got {trace_length}")
+        self._trace: deque[dict] = deque(maxlen=trace_length)
+        self._record_candidates = record_candidates
+        if candidate_log_length < 1:
+            raise ConfigError(f"candidate_log_length must be >= 1, got {candidate_log_length}")
+        self._candidate_log: deque[dict] = deque(maxlen=candidate_log_length)
+        self._morph_sos: np.ndarray | None = None
+        self._morph_rate: float = 0.0
+        self.band_name = band_name
+        self.role = role
         self._chunks_seen = 0
+        self._last_waveform: dict | None = None
+
+        if target_rate_per_min is not None and target_rate_per_min <= 0:
+            raise ConfigError(f"target_rate_per_min must be > 0, got {target_rate_per_min}")
+        if rate_window_s <= 0 or adapt_gain <= 0:
+            raise ConfigError("rate_window_s and adapt_gain must be > 0")
+        self._target_rate = target_rate_per_min
+        self._rate_window_s = rate_window_s
+        self._adapt_gain = adapt_gain
+        if amp_min_floor is None:
+            amp_min_floor = amp_min * _AMP_MIN_FLOOR_FACTOR
+        if not 0.0 < amp_min_floor <= amp_min:
+            raise ConfigError(f"amp_min_floor must be in (0, amp_min], got {amp_min_floor}")
+        self._amp_min_floor = amp_min_floor
+        self._amp_min_eff = amp_min          # amp_min in use (adapted if target set)
+        self._onsets: deque[float] = deque()
+        self._adapt_start: float | None = None
+        self._was_active = False
+
+        self._track_error = track_prediction_error
+        self._pending_target: tuple[float, float] | None = None  # (time, period)
+        self._prediction_errors: list[float] = []
+
+    @property
+    def effective_amp_min(self) -> float:
+        """amp_min currently applied (differs from amp_min when adapting)."""
+        return self._amp_min_eff
+
+    @property
+    def trace(self) -> list[dict]:
+        """Per-chunk decision state (debug=True only), oldest first.
+
+        Holds the last trace_length chunks.
+        """
+        return list(self._trace)
+
+    @property
+    def candidate_log(self) -> list[dict]:
+        """Scores of validated candidates (record_candidates=True only), oldest first.
+
+        Holds the last candidate_log_length candidates.
+        """
+        return list(self._candidate_log)
+
+    @property
+    def prediction_errors(self) -> list[float]:
+        """Actual − predicted extremum time in ms (track_prediction_error only)."""
+        return self._prediction_errors
+
+    @property
+    def last_waveform(self) -> dict | None:
+        """Raw signal + fitted sinusoid of the most recent detection, or None."""
+        return self._last_waveform
+
+    def describe(self) -> str:
+        return (
+            f"TWaveDetector '{self.id}': "
+            f"{describe_band(self._freq_range, self.band_name, self.role)}"
+        )
 
     def configure(self, config: PipelineConfig) -> None:
+        # Cleared here, not in reset(): the pipeline resets modules at
+        # teardown and these should stay readable after a run.
+        self._last_waveform = None
+        self._trace.clear()
+        self._candidate_log.clear()
+        self._prediction_errors = []
         logger.info(
             "TWaveDetector '%s': freq=(%.1f,%.1f), target_phase=%.2f rad (%.0f°), "
             "predict_limit=%.0f ms, amp=[%.0f,%.0f] µV",
@@ -91,6 +278,85 @@ class TWaveDetector(Module):
         )
 
     def process(self, result: ProcessResult) -> ProcessResult:
+        result = self._evaluate(result)
+        if (self._target_rate is not None and result.chunk.n_samples > 0
+                and result.wavelet_settled and self._chunks_seen > self._warmup_chunks):
+            self._adapt_threshold(result)
+        if self._track_error and result.chunk.n_samples > 0:
+            self._track_prediction(result)
+        if self._debug and result.chunk.n_samples > 0:
+            state = result.detections[self.id]
+            self._trace.append({
+                "timestamp": float(result.chunk.timestamps[-1]),
+                **{k: v for k, v in state.items() if k != "candidates"},
+            })
+        return result
+
+    def _adapt_threshold(self, result: ProcessResult) -> None:
+        """Nudge amp_min toward the target detection rate.
+
+        Only onsets count, so a wave validated on two consecutive chunks
+        is one detection. No change until a full rate window has passed.
+        """
+        chunk = result.chunk
+        t_now = float(chunk.timestamps[-1])
+        active = result.detections[self.id].get("active", False)
+        if active and not self._was_active:
+            self._onsets.append(t_now)
+        self._was_active = active
+        while self._onsets and self._onsets[0] <= t_now - self._rate_window_s:
+            self._onsets.popleft()
+
+        if self._adapt_start is None:
+            self._adapt_start = t_now
+        if t_now - self._adapt_start < self._rate_window_s:
+            return
+        rate = len(self._onsets) * 60.0 / self._rate_window_s
+        error = (rate - self._target_rate) / self._target_rate
+        step = self._adapt_gain * chunk.n_samples / chunk.sample_rate
+        # Too many detections → raise amp_min; too few → lower it
+        self._amp_min_eff = min(
+            max(self._amp_min_eff * exp(step * error), self._amp_min_floor), self._amp_max,
+        )
+        result.detections[self.id]["amp_min"] = self._amp_min_eff
+
+    def _track_prediction(self, result: ProcessResult) -> None:
+        """Resolve the pending prediction once ¼ period past it, then take the next.
+
+        Predictions aim at target_phase; the reference is the extremum
+        nearest it (peak for phase 0, trough for π), with the predicted
+        time shifted by the phase between the two.
+        """
+        chunk = result.chunk
+        t_now = float(chunk.timestamps[-1])
+        fs = chunk.sample_rate
+        if self._pending_target is not None:
+            t_ref, period = self._pending_target
+            half_width = period / 4.0
+            if t_now >= t_ref + half_width:
+                self._pending_target = None
+                n = int(round((t_now - t_ref + half_width) * fs)) + 1
+                if result.ring_buffer is not None and n <= result.ring_buffer.available:
+                    recent = self._lowpass(result.ring_buffer.read_latest(n), fs)
+                    if recent is not None:
+                        times = t_now - np.arange(n)[::-1] / fs
+                        in_window = times <= t_ref + half_width
+                        seg = recent[in_window]
+                        peak = np.cos(self._target_phase) >= 0
+                        i = int(np.argmax(seg) if peak else np.argmin(seg))
+                        error_ms = (float(times[in_window][i]) - t_ref) * 1000.0
+                        self._prediction_errors.append(error_ms)
+                        result.detections[self.id]["prediction_error_ms"] = error_ms
+
+        state = result.detections[self.id]
+        if self._pending_target is None and state.get("active"):
+            c = state["candidates"][0]
+            ref_phase = 0.0 if np.cos(self._target_phase) >= 0 else pi
+            shift = (ref_phase - self._target_phase + pi) % (2 * pi) - pi
+            t_ref = c["timestamp"] + shift / (2 * pi * c["frequency"])
+            self._pending_target = (t_ref, 1.0 / c["frequency"])
+
+    def _evaluate(self, result: ProcessResult) -> ProcessResult:
         self._chunks_seen += 1
 
         if result.wavelet is None or not result.wavelet_settled:
@@ -101,6 +367,12 @@ class TWaveDetector(Module):
             result.detections[self.id] = {"active": False, "candidates": [], "warming_up": True}
             return result
 
+        if not result.epoch_active:
+            result.detections[self.id] = {
+                "active": False, "candidates": [], "reject_reason": "epoch",
+            }
+            return result
+
         wavelet = result.wavelet
         chunk = result.chunk
         freqs = wavelet.frequencies
@@ -134,7 +406,18 @@ class TWaveDetector(Module):
         if delta_phi < 1e-6:
             delta_phi = 2 * pi  # target is ~now, wait for next cycle
 
-        dt = delta_phi / (2 * pi * freq_now)
+        # Smoothed period (EMA over successive estimates) for the prediction
+        freq_pred = freq_now
+        if self._period_smoothing is not None:
+            period_now = 1.0 / freq_now
+            if self._period_ema is None:
+                self._period_ema = period_now
+            else:
+                self._period_ema += self._period_smoothing * (period_now - self._period_ema)
+            freq_pred = 1.0 / self._period_ema
+
+        dt_raw = delta_phi / (2 * pi * freq_now)
+        dt = delta_phi / (2 * pi * freq_pred)
 
         # If target is too far out, don't predict — unreliable
         if dt > self._prediction_limit_s:
@@ -147,87 +430,185 @@ class TWaveDetector(Module):
             return result
 
         # ── 3. Multi-feature validation ───────────────────────────────
+        # Scores are computed before deciding so record_candidates can log
+        # every candidate with all of them; rejection order is unchanged.
+        scoring = self._record_candidates
+        state = {
+            "phase_now": phase_now, "freq_now": freq_now,
+            "amplitude": amplitude, "dt": dt,
+            "hilo_ratio": (
+                self._hilo_ratio(freqs, amp_now)
+                if self._hilo_ratio_max is not None or scoring else None
+            ),
+            "template_score": (
+                self._template_score(result, freq_now, phase_now, amplitude)
+                if self._template_threshold is not None or scoring else None
+            ),
+        }
 
+        reject_reason = None
         # (a) Amplitude bounds
-        if amplitude < self._amp_min or amplitude > self._amp_max:
+        if amplitude < self._amp_min_eff or amplitude > self._amp_max:
+            reject_reason = "amplitude"
+        # (b) High-to-low frequency ratio (IED rejection)
+        elif (self._hilo_ratio_max is not None and state["hilo_ratio"] is not None
+              and state["hilo_ratio"] > self._hilo_ratio_max):
+            reject_reason = "hilo_ratio"
+        # (c) Template matching — dot product of recent signal vs ideal SO
+        elif (self._template_threshold is not None and state["template_score"] is not None
+              and state["template_score"] < self._template_threshold):
+            reject_reason = "template"
+
+        if self._record_candidates:
+            self._candidate_log.append({
+                "timestamp": t_now, **state,
+                "passed": reject_reason is None, "reject_reason": reject_reason,
+            })
+
+        if reject_reason is not None:
             result.detections[self.id] = {
-                "active": False, "candidates": [],
-                "phase_now": phase_now, "freq_now": freq_now,
-                "amplitude": amplitude, "dt": dt,
-                "reject_reason": "amplitude",
+                "active": False, "candidates": [], **state,
+                "reject_reason": reject_reason,
             }
             return result
 
-        # (b) High-to-low frequency ratio (IED rejection)
-        if self._hilo_ratio_max is not None:
-            hi_mask = freqs >= self._hilo_boundary_hz
-            lo_mask = freqs < self._hilo_boundary_hz
-            if np.any(hi_mask) and np.any(lo_mask):
-                hi_power = float(np.mean(amp_now[hi_mask]))
-                lo_power = float(np.mean(amp_now[lo_mask]))
-                ratio = hi_power / lo_power if lo_power > 0 else float("inf")
-                if ratio > self._hilo_ratio_max:
-                    result.detections[self.id] = {
-                        "active": False, "candidates": [],
-                        "phase_now": phase_now, "freq_now": freq_now,
-                        "amplitude": amplitude, "dt": dt,
-                        "reject_reason": "hilo_ratio",
-                        "hilo_ratio": ratio,
-                    }
-                    return result
-
-        # (c) Template matching — dot product of recent signal vs ideal SO
-        if self._template_threshold is not None and result.ring_buffer is not None:
-            template_samples = int(self._template_window_s * chunk.sample_rate)
-            if result.ring_buffer.available >= template_samples:
-                recent = result.ring_buffer.read_latest(template_samples)
-
-                # Normalize
-                recent_norm = recent - np.mean(recent)
-                r_std = np.std(recent_norm)
-                if r_std > 0:
-                    recent_norm /= r_std
-
-                    # Generate ideal sinusoid at detected frequency and phase
-                    t_template = np.arange(template_samples) / chunk.sample_rate
-                    # Phase at the start of the template window:
-                    #   phase_now is at the end, so rewind by template duration
-                    phase_start = phase_now - 2 * pi * freq_now * self._template_window_s
-                    ideal = np.cos(2 * pi * freq_now * t_template + phase_start)
-
-                    # Normalized dot product
-                    match_score = float(np.dot(recent_norm, ideal) / template_samples)
-
-                    if match_score < self._template_threshold:
-                        result.detections[self.id] = {
-                            "active": False, "candidates": [],
-                            "phase_now": phase_now, "freq_now": freq_now,
-                            "amplitude": amplitude, "dt": dt,
-                            "reject_reason": "template",
-                            "template_score": match_score,
-                        }
-                        return result
-
         # ── 4. All checks passed — emit candidate ────────────────────
         t_predicted = t_now + dt
 
         candidate = {
             "timestamp": t_predicted,
-            "frequency": freq_now,
+            "timestamp_raw": t_now + dt_raw,
+            "frequency": freq_pred,
+            "frequency_raw": freq_now,
+            "period_ms": 1000.0 / freq_pred,
             "amplitude": amplitude,
             "phase_now": phase_now,
             "dt_to_target_ms": dt * 1000,
             "channel_id": chunk.channel_id,
+            "hilo_ratio": state["hilo_ratio"],
+            "template_score": state["template_score"],
         }
 
+        if result.ring_buffer is not None:
+            n_morph = min(
+                int(self._template_window_s * chunk.sample_rate),
+                result.ring_buffer.available,
+            )
+            morph = self._morphology(result.ring_buffer.read_latest(n_morph), chunk.sample_rate)
+            if morph is not None:
+                candidate.update(morph)
+
+        if self._retain_waveform and result.ring_buffer is not None:
+            n_wave = min(
+                int(self._template_window_s * chunk.sample_rate),
+                result.ring_buffer.available,
+            )
+            raw = result.ring_buffer.read_latest(n_wave)
+            ideal = self._ideal_sinusoid(n_wave, chunk.sample_rate, freq_now, phase_now)
+            self._last_waveform = {
+                "timestamps": t_now - np.arange(n_wave)[::-1] / chunk.sample_rate,
+                "raw": raw,
+                # Fitted sinusoid scaled to the raw window's RMS amplitude
+                "template": ideal * np.std(raw) * np.sqrt(2),
+                "frequency": freq_now,
+            }
+            candidate["waveform"] = self._last_waveform
+
         result.detections[self.id] = {
             "active": True,
             "candidates": [candidate],
             "phase_now": phase_now,
             "freq_now": freq_now,
+            "period_ms": candidate["period_ms"],
             "amplitude": amplitude,
         }
         return result
 
+    def _hilo_ratio(
+        self, freqs: NDArray[np.float64], amp_now: NDArray[np.float64],
+    ) -> float | None:
+        """High- to low-frequency wavelet amplitude ratio at the latest sample."""
+        hi_mask = freqs >= self._hilo_boundary_hz
+        lo_mask = freqs < self._hilo_boundary_hz
+        if not (np.any(hi_mask) and np.any(lo_mask)):
+            return None
+        hi_power = float(np.mean(amp_now[hi_mask]))
+        lo_power = float(np.mean(amp_now[lo_mask]))
+        return hi_power / lo_power if lo_power > 0 else float("inf")
+
+    def _template_score(
+        self, result: ProcessResult, freq_now: float, phase_now: float, amplitude: float,
+    ) -> float | None:
+        """Match of the recent signal to the ideal SO, per template_metric."""
+        if result.ring_buffer is None:
+            return None
+        sample_rate = result.chunk.sample_rate
+        template_samples = int(self._template_window_s * sample_rate)
+        if result.ring_buffer.available < template_samples:
+            return None
+        recent = result.ring_buffer.read_latest(template_samples)
+        ideal = self._ideal_sinusoid(template_samples, sample_rate, freq_now, phase_now)
+        centred = recent - np.mean(recent)
+
+        if self._template_metric == "nrmse":
+            # Sinusoid at the wavelet amplitude (µV), so scale matters too
+            fitted = amplitude * ideal
+            ref_rms = float(np.sqrt(np.mean(fitted ** 2)))
+            if ref_rms <= 0:
+                return None
+            return 1.0 - float(np.sqrt(np.mean((centred - fitted) ** 2))) / ref_rms
+
+        r_std = np.std(centred)
+        if r_std <= 0:
+            return None
+        if self._template_metric == "cosine":
+            return float(np.dot(centred, ideal) / (np.linalg.norm(centred) * np.linalg.norm(ideal)))
+        # dot: z-scored signal against the unit cosine
+        return float(np.dot(centred / r_std, ideal) / template_samples)
+
+    def _morphology(
+        self, recent: NDArray[np.float64], sample_rate: float,
+    ) -> dict[str, float] | None:
+        """wave_morphology on the recent signal, low-passed to keep the SO shape."""
+        recent = self._lowpass(recent, sample_rate)
+        if recent is None:
+            return None
+        return wave_morphology(recent, sample_rate)
+
+    def _lowpass(
+        self, recent: NDArray[np.float64], sample_rate: float,
+    ) -> NDArray[np.float64] | None:
+        """Zero-phase low-pass of a past window; None if too short to filter."""
+        if abs(sample_rate - self._morph_rate) > 0.1:
+            # 4× the SO band edge keeps asymmetric waveshapes, drops spikes/noise
+            cutoff = 4.0 * self._freq_range[1]
+            self._morph_sos = (
+                butter(2, cutoff, btype="low", output="sos", fs=sample_rate)
+                if cutoff < sample_rate / 2.0 else None
+            )
+            self._morph_rate = sample_rate
+        if self._morph_sos is not None:
+            if recent.shape[0] <= 16:
+                return None
+            recent = sosfiltfilt(self._morph_sos, recent)
+        return recent
+
+    @staticmethod
+    def _ideal_sinusoid(
+        n_samples: int, sample_rate: float, freq: float, phase_now: float,
+    ) -> NDArray[np.float64]:
+        """Unit cosine at freq whose phase at the last sample is phase_now."""
+        t = np.arange(n_samples) / sample_rate
+        # Phase at the start of the window:
+        #   phase_now is at the end, so rewind by the window duration
+        phase_start = phase_now - 2 * pi * freq * n_samples / sample_rate
+        return np.cos(2 * pi * freq * t + phase_start)
+
     def reset(self) -> None:
-        self._chunks_seen = 0
\ No newline at end of file
+        self._chunks_seen = 0
+        self._period_ema = None
+        self._amp_min_eff = self._amp_min
+        self._onsets.clear()
+        self._adapt_start = None
+        self._was_active = False
+        self._pending_target = None
\ No newline at end of file
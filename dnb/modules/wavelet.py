@@ -23,6 +23,7 @@ from numpy.typing import NDArray
 from scipy.fft import fft, ifft, next_fast_len
 
 from dnb.core.types import DataChunk, PipelineConfig, WaveletResult
+from dnb.errors import DnbRuntimeError
 from dnb.modules.base import Module, ProcessResult
 
 logger = logging.getLogger(__name__)
@@ -76,13 +77,19 @@ class WaveletConvolution(Module):
     @property
     def frequencies(self) -> NDArray[np.float64]:
         if self._frequencies is None:
-            raise RuntimeError("Module not configured yet.")
+            raise DnbRuntimeError("Module not configured yet.")
         return self._frequencies
 
     @property
     def max_kernel_half_len(self) -> int:
         return self._max_kernel_half_len
 
+    def describe(self) -> str:
+        return (
+            f"WaveletConvolution: {self._n_freqs} freqs, "
+            f"{self._freq_min:g}–{self._freq_max:g} Hz"
+        )
+
     def configure(self, config: PipelineConfig) -> None:
         self._frequencies = np.geomspace(self._freq_min, self._freq_max, self._n_freqs)
         self._n_cycles = self._n_cycles_base * (self._frequencies / self._freq_min)
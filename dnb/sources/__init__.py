@@ -1,5 +1,10 @@
+from dnb.sources.array import ArraySource
 from dnb.sources.base import DataSource
+from dnb.sources.edf import EdfHeader, EdfSource, read_edf_channel, read_edf_header
 from dnb.sources.file import FileSource
 
 # Live sources imported lazily (require pycbsdk)
-__all__ = ["DataSource", "FileSource"]
\ No newline at end of file
+__all__ = [
+    "ArraySource", "DataSource", "EdfHeader", "EdfSource", "FileSource",
+    "read_edf_channel", "read_edf_header",
+]
\ No newline at end of file
@@ -0,0 +1,94 @@
+"""In-memory data source — serves a 1D numpy array chunk by chunk.
+
+For offline analysis of signals already in memory (synthetic data,
+slices of a recording) without writing an .npz first.
+"""
+
+from __future__ import annotations
+
+import logging
+from dataclasses import replace
+
+import numpy as np
+from numpy.typing import NDArray
+
+from dnb.core.types import DataChunk, PipelineConfig
+from dnb.errors import DnbRuntimeError, ValidationError
+from dnb.sources.base import DataSource
+
+logger = logging.getLogger(__name__)
+
+
+class ArraySource(DataSource):
+    """Reads continuous data from a 1D array.
+
+    Args:
+        samples: 1D signal.
+        sample_rate: Rate of `samples` in Hz (overrides PipelineConfig).
+        t0: Timestamp of the first sample, so slices of a longer
+            recording keep absolute times.
+        channel_id: Reported channel id.
+    """
+
+    def __init__(
+        self,
+        samples: NDArray[np.float64],
+        sample_rate: float,
+        t0: float = 0.0,
+        channel_id: int = 0,
+    ) -> None:
+        samples = np.asarray(samples, dtype=np.float64)
+        if samples.ndim != 1:
+            raise ValidationError(f"ArraySource expects a 1D signal, got shape {samples.shape}")
+        self._samples = samples
+        self._sample_rate = float(sample_rate)
+        self._t0 = t0
+        self._channel_id = channel_id
+        self._data: NDArray[np.float64] | None = None
+        self._read_pos: int = 0
+        self._chunk_samples: int = 0
+        self._resolved_config: PipelineConfig | None = None
+
+    @property
+    def resolved_config(self) -> PipelineConfig | None:
+        return self._resolved_config
+
+    def connect(self, config: PipelineConfig) -> None:
+        self._data = self._samples
+        self._read_pos = 0
+        self._chunk_samples = max(1, int(config.chunk_duration * self._sample_rate))
+        self._resolved_config = replace(
+            config, sample_rate=self._sample_rate, channel_id=self._channel_id,
+        )
+        logger.debug(
+            "ArraySource: %d samples @ %.0f Hz from t0=%.3fs",
+            self._data.shape[0], self._sample_rate, self._t0,
+        )
+
+    def read_chunk(self) -> DataChunk | None:
+        if self._data is None:
+            raise DnbRuntimeError("Source not connected.")
+        if self._read_pos >= self._data.shape[0]:
+            return None
+
+        end = min(self._read_pos + self._chunk_samples, self._data.shape[0])
+        samples = self._data[self._read_pos:end]
+        timestamps = self._t0 + (self._read_pos + np.arange(samples.shape[0])) / self._sample_rate
+        self._read_pos = end
+
+        return DataChunk(
+            samples=samples,
+            timestamps=timestamps,
+            channel_id=self._channel_id,
+            sample_rate=self._sample_rate,
+        )
+
+    def close(self) -> None:
+        self._data = None
+        self._read_pos = 0
+
+    @property
+    def progress(self) -> float:
+        if self._samples.shape[0] == 0:
+            return 0.0
+        return self._read_pos / self._samples.shape[0]
@@ -0,0 +1,193 @@
+"""EDF / EDF+ data source — reads one channel of a European Data Format file.
+
+EDF stores fixed-duration data records; each signal contributes its own
+number of int16 samples per record, so channels may have different
+rates. Samples are scaled digital → physical with each signal's
+digital_min/max and physical_min/max, then converted to µV from the
+header's physical dimension (uV, mV, V). EDF+ "EDF Annotations" signals
+are not data and are not selectable.
+"""
+
+from __future__ import annotations
+
+import logging
+from dataclasses import dataclass, replace
+from pathlib import Path
+
+import numpy as np
+from numpy.typing import NDArray
+
+from dnb.core.types import PipelineConfig
+from dnb.errors import DnbIOError, ValidationError
+from dnb.sources.file import FileSource
+
+logger = logging.getLogger(__name__)
+
+_ANNOTATIONS = "EDF Annotations"
+_TO_UV = {"uv": 1.0, "µv": 1.0, "μv": 1.0, "mv": 1e3, "v": 1e6}
+
+
+@dataclass
+class EdfHeader:
+    """Fixed and per-signal EDF header fields."""
+
+    header_bytes: int
+    n_records: int
+    record_duration: float
+    labels: list[str]
+    units: list[str]
+    physical_min: NDArray[np.float64]
+    physical_max: NDArray[np.float64]
+    digital_min: NDArray[np.float64]
+    digital_max: NDArray[np.float64]
+    samples_per_record: NDArray[np.int64]
+
+    @property
+    def sample_rates(self) -> NDArray[np.float64]:
+        return self.samples_per_record / self.record_duration
+
+    @property
+    def channel_labels(self) -> list[str]:
+        """Data signal labels (annotation signals excluded)."""
+        return [lb for lb in self.labels if lb != _ANNOTATIONS]
+
+
+def read_edf_header(path: str | Path) -> EdfHeader:
+    """Parse the header of an EDF/EDF+ file."""
+    path = Path(path)
+    try:
+        with open(path, "rb") as f:
+            fixed = f.read(256)
+            if len(fixed) < 256:
+                raise ValidationError(f"{path.name}: too short for an EDF header")
+            ns = int(_field(fixed, 252, 4))
+            per_signal = f.read(ns * 256)
+            file_size = path.stat().st_size
+    except OSError as e:
+        raise DnbIOError(f"Could not read {path}: {e}") from e
+    if len(per_signal) < ns * 256:
+        raise ValidationError(f"{path.name}: truncated EDF signal header")
+
+    def column(offset: int, width: int) -> list[str]:
+        base = offset * ns
+        return [_field(per_signal, base + i * width, width) for i in range(ns)]
+
+    def numbers(offset: int, width: int) -> NDArray[np.float64]:
+        return np.array([float(v) for v in column(offset, width)])
+
+    # Per-signal fields are stored field by field, each repeated ns times
+    labels = column(0, 16)
+    units = column(96, 8)
+    header = EdfHeader(
+        header_bytes=int(_field(fixed, 184, 8)),
+        n_records=int(_field(fixed, 236, 8)),
+        record_duration=float(_field(fixed, 244, 8)),
+        labels=labels,
+        units=units,
+        physical_min=numbers(104, 8),
+        physical_max=numbers(112, 8),
+        digital_min=numbers(120, 8),
+        digital_max=numbers(128, 8),
+        samples_per_record=numbers(216, 8).astype(np.int64),
+    )
+    if header.record_duration <= 0:
+        raise ValidationError(f"{path.name}: record duration must be > 0")
+
+    # n_records is -1 while a recording is still being written
+    record_bytes = 2 * int(header.samples_per_record.sum())
+    available = (file_size - header.header_bytes) // record_bytes if record_bytes else 0
+    if header.n_records < 0 or header.n_records > available:
+        header.n_records = int(available)
+    return header
+
+
+def read_edf_channel(
+    path: str | Path, channel: str | int,
+) -> tuple[NDArray[np.float64], float]:
+    """Read one data channel, by label or index, as µV.
+
+    Returns:
+        (samples, sample_rate)
+    """
+    path = Path(path)
+    header = read_edf_header(path)
+    data_labels = header.channel_labels
+    if isinstance(channel, str):
+        if channel not in data_labels:
+            raise ValidationError(
+                f"{path.name}: no channel {channel!r} (channels: {', '.join(data_labels)})"
+            )
+        label = channel
+    else:
+        if not 0 <= channel < len(data_labels):
+            raise ValidationError(
+                f"{path.name}: channel index {channel} out of range "
+                f"({len(data_labels)} data channel(s): {', '.join(data_labels)})"
+            )
+        label = data_labels[channel]
+    idx = header.labels.index(label)
+
+    spr = header.samples_per_record
+    record_len = int(spr.sum())
+    try:
+        raw = np.fromfile(
+            str(path), dtype="<i2", count=header.n_records * record_len,
+            offset=header.header_bytes,
+        )
+    except (OSError, ValueError) as e:
+        raise DnbIOError(f"Could not read {path}: {e}") from e
+    start = int(spr[:idx].sum())
+    digital = raw.reshape(header.n_records, record_len)[:, start:start + spr[idx]].ravel()
+
+    d_min, d_max = header.digital_min[idx], header.digital_max[idx]
+    p_min, p_max = header.physical_min[idx], header.physical_max[idx]
+    if d_max == d_min:
+        raise ValidationError(f"{path.name}: channel {label!r} has digital_min == digital_max")
+    samples = (digital.astype(np.float64) - d_min) * ((p_max - p_min) / (d_max - d_min)) + p_min
+
+    unit = header.units[idx]
+    scale = _TO_UV.get(unit.lower())
+    if scale is None:
+        logger.warning("EDF: unknown unit %r on %r — using values as-is", unit, label)
+    else:
+        samples *= scale
+    return samples, float(header.sample_rates[idx])
+
+
+def _field(buf: bytes, offset: int, width: int) -> str:
+    return buf[offset:offset + width].decode("latin-1").strip()
+
+
+class EdfSource(FileSource):
+    """Reads one channel of an EDF/EDF+ file.
+
+    Args:
+        path: .edf file.
+        channel_label: Signal label to read (e.g. "EEG Fp1"). If None,
+            PipelineConfig.channel_id indexes the data channels.
+    """
+
+    def __init__(self, path: str | Path, channel_label: str | None = None) -> None:
+        super().__init__(path)
+        self._channel_label = channel_label
+
+    def connect(self, config: PipelineConfig) -> None:
+        if not self._path.exists():
+            raise DnbIOError(f"Data file not found: {self._path}")
+
+        self._channel_id = config.channel_id
+        channel = self._channel_label if self._channel_label is not None else self._channel_id
+        self._data, self._sample_rate = read_edf_channel(self._path, channel)
+
+        self._total_samples = self._data.shape[0]
+        self._read_pos = 0
+        self._chunk_samples = int(config.chunk_duration * self._sample_rate)
+        self._resolved_config = replace(
+            config, sample_rate=self._sample_rate, channel_id=self._channel_id,
+        )
+
+        logger.info(
+            "EdfSource: %s (channel %r, %.1fs @ %.0f Hz, chunk=%d samples)",
+            self._path.name, channel, self._total_samples / self._sample_rate,
+            self._sample_rate, self._chunk_samples,
+        )
@@ -15,11 +15,13 @@ Supports two .npz layouts:
 from __future__ import annotations
 
 import logging
+from dataclasses import replace
 from pathlib import Path
 
 import numpy as np
 
 from dnb.core.types import DataChunk, PipelineConfig
+from dnb.errors import DnbIOError, DnbRuntimeError, ValidationError
 from dnb.sources.base import DataSource
 
 logger = logging.getLogger(__name__)
@@ -44,9 +46,12 @@ class FileSource(DataSource):
 
     def connect(self, config: PipelineConfig) -> None:
         if not self._path.exists():
-            raise FileNotFoundError(f"Data file not found: {self._path}")
+            raise DnbIOError(f"Data file not found: {self._path}")
 
-        npz = np.load(str(self._path), allow_pickle=True)
+        try:
+            npz = np.load(str(self._path), allow_pickle=True)
+        except (OSError, ValueError) as e:
+            raise DnbIOError(f"Could not read {self._path}: {e}") from e
         keys = list(npz.keys())
         self._channel_id = config.channel_id
 
@@ -89,7 +94,7 @@ class FileSource(DataSource):
             logger.info("FileSource: synthetic format (keys: %s)", ', '.join(keys))
 
         else:
-            raise KeyError(
+            raise ValidationError(
                 f"Unrecognised .npz format. Keys: {keys}. "
                 f"Expected 'data' (ns6) or 'continuous' (synthetic)."
             )
@@ -98,11 +103,8 @@ class FileSource(DataSource):
         self._read_pos = 0
         self._chunk_samples = int(config.chunk_duration * self._sample_rate)
 
-        self._resolved_config = PipelineConfig(
-            sample_rate=self._sample_rate,
-            channel_id=self._channel_id,
-            buffer_duration=config.buffer_duration,
-            chunk_duration=config.chunk_duration,
+        self._resolved_config = replace(
+            config, sample_rate=self._sample_rate, channel_id=self._channel_id,
         )
 
         duration = self._total_samples / self._sample_rate
@@ -114,7 +116,7 @@ class FileSource(DataSource):
 
     def read_chunk(self) -> DataChunk | None:
         if self._data is None:
-            raise RuntimeError("Source not connected.")
+            raise DnbRuntimeError("Source not connected.")
         if self._read_pos >= self._total_samples:
             return None
 
@@ -7,6 +7,7 @@ Usage:
     python run.py -c config.yaml --detect-only      # live, no stim
     python run.py -c config.yaml --offline          # offline from file
     python run.py -c config.yaml --offline --detect-only
+    python run.py -c config.yaml --diff other.yaml  # compare two configs
 """
 
 from __future__ import annotations
@@ -23,8 +24,12 @@ from pathlib import Path
 import numpy as np
 
 import dnb
-from dnb.config import build_modules, build_pipeline_config, build_source, load_config
+from dnb.config import (
+    build_modules, build_pipeline_config, build_source, check_config, diff_configs,
+    load_config,
+)
 from dnb.core.types import Event, EventType, PipelineConfig
+from dnb.errors import ConfigError, DnbError, DnbRuntimeError
 from dnb.engine.pipeline import Pipeline
 
 logger = logging.getLogger("dnb.run")
@@ -48,13 +53,23 @@ def setup_logging(level=logging.INFO):
 # ── Event logger ─────────────────────────────────────────────────────────
 
 class EventLogger:
-    """Logs events to JSONL (crash-safe) and accumulates for .npz save."""
+    """Logs events to JSONL (crash-safe) and accumulates for .npz save.
 
-    def __init__(self, output_dir: Path, session_name: str):
+    With max_bytes set, the JSONL file is rotated once it grows past
+    that size: `<name>_events.jsonl` → `<name>_events.1.jsonl` → ...,
+    keeping at most max_files rotated files (oldest deleted).
+    """
+
+    def __init__(
+        self, output_dir: Path, session_name: str,
+        max_bytes: int | None = None, max_files: int = 5,
+    ):
         self.output_dir = output_dir
         self.output_dir.mkdir(parents=True, exist_ok=True)
         self.session_name = session_name
         self._events: list[Event] = []
+        self._max_bytes = max_bytes
+        self._max_files = max(1, max_files)
 
         self._log_path = output_dir / f"{session_name}_events.jsonl"
         self._log_file = open(self._log_path, "w")
@@ -69,14 +84,40 @@ class EventLogger:
             "channel_id": event.channel_id,
         }
         # TWave metadata keys
-        for key in ("pulse_index", "n_pulses", "frequency", "amplitude",
-                     "phase_now", "dt_to_stim_ms",
-                     "detection_time", "power", "active"):
+        for key in ("pulse_index", "n_pulses", "frequency", "period_ms", "amplitude",
+                     "phase_now", "dt_to_stim_ms", "stim_clamped",
+                     "downslope", "upslope", "symmetry", "stimulation_enabled",
+                     "detection_time", "power", "active",
+                     "detector_id", "duration_ms", "peak_z", "activation_id",
+                     "trigger_id"):
             if key in event.metadata:
                 record[key] = event.metadata[key]
 
+        # Retained detection waveform (TWaveDetector retain_waveform=True)
+        waveform = event.metadata.get("waveform")
+        if waveform is not None:
+            record["waveform"] = {
+                k: (v.tolist() if isinstance(v, np.ndarray) else v)
+                for k, v in waveform.items()
+            }
+
         self._log_file.write(json.dumps(record) + "\n")
         self._log_file.flush()
+        if self._max_bytes is not None and self._log_file.tell() >= self._max_bytes:
+            self._rotate()
+
+    def _rotated_path(self, index: int) -> Path:
+        return self.output_dir / f"{self.session_name}_events.{index}.jsonl"
+
+    def _rotate(self) -> None:
+        self._log_file.close()
+        # Shift .1 → .2 → ...; the file at max_files is overwritten (pruned)
+        for i in range(self._max_files - 1, 0, -1):
+            if self._rotated_path(i).exists():
+                self._rotated_path(i).replace(self._rotated_path(i + 1))
+        self._log_path.replace(self._rotated_path(1))
+        self._log_file = open(self._log_path, "w")
+        logger.info("Event log rotated (> %d bytes)", self._max_bytes)
 
     def save_npz(self) -> Path | None:
         if not self._events:
@@ -112,6 +153,13 @@ class EventLogger:
         return f"{len(self._events)} events ({', '.join(parts)})"
 
 
+def log_rotation(args: argparse.Namespace) -> dict:
+    """EventLogger rotation kwargs from --log-max-mb / --log-max-files."""
+    if args.log_max_mb is None:
+        return {}
+    return {"max_bytes": int(args.log_max_mb * 1024 * 1024), "max_files": args.log_max_files}
+
+
 # ── Apply CLI overrides to config ────────────────────────────────────────
 
 def apply_overrides(cfg: dict, args: argparse.Namespace) -> None:
@@ -125,7 +173,7 @@ def apply_overrides(cfg: dict, args: argparse.Namespace) -> None:
     if args.channel is not None:
         if "pipeline" not in cfg:
             cfg["pipeline"] = {}
-        cfg["pipeline"]["channel_index"] = args.channel
+        cfg["pipeline"]["channel_id"] = args.channel
         logger.info("--channel: %d", args.channel)
 
 
@@ -138,7 +186,7 @@ def build_source_live(cfg: dict, source_override: str | None = None):
     source_type = source_type.lower()
 
     if source_type == "file":
-        raise ValueError(
+        raise ConfigError(
             "source.type is 'file' — use --offline, or change to nplay/cerebus."
         )
 
@@ -166,14 +214,19 @@ def build_source_live(cfg: dict, source_override: str | None = None):
             if source_type == "cerebus":
                 raise ImportError("pycbsdk not installed.") from e
 
-    raise RuntimeError(f"No live source available (tried: {source_type}).")
+    raise DnbRuntimeError(f"No live source available (tried: {source_type}).")
 
 
 # ── Status printer ───────────────────────────────────────────────────────
 
 class StatusPrinter:
-    def __init__(self, event_logger: EventLogger, interval_s: float = 10.0):
+    def __init__(
+        self, event_logger: EventLogger, interval_s: float = 10.0,
+        scheduler=None, pipeline: Pipeline | None = None,
+    ):
         self._event_logger = event_logger
+        self._scheduler = scheduler
+        self._pipeline = pipeline
         self._interval_s = interval_s
         self._last_print = time.perf_counter()
         self._chunk_count = 0
@@ -184,9 +237,24 @@ class StatusPrinter:
         now = time.perf_counter()
         if now - self._last_print >= self._interval_s:
             elapsed = now - self._start_time
+            next_stim = ""
+            if self._scheduler is not None:
+                t_next = self._scheduler.time_until_next_stim()
+                if t_next >= 0:
+                    next_stim = f"  next stim in {t_next * 1000:.0f} ms"
+            triggers = ""
+            latency = ""
+            if self._pipeline is not None:
+                lat = self._pipeline.processing_stats()
+                latency = f"  latency {lat['mean_ms']:.1f}/{lat['max_ms']:.1f} ms"
+                for trig_id, s in self._pipeline.trigger_stats().items():
+                    triggers += (
+                        f"  [{trig_id}] stims={s['stims']} suppressed={s['suppressed']} "
+                        f"inhibited={s['inhibited']}"
+                    )
             print(
                 f"  [{elapsed:7.1f}s] chunks={self._chunk_count:6d}  "
-                f"{self._event_logger.summary()}",
+                f"{self._event_logger.summary()}{triggers}{latency}{next_stim}",
                 flush=True,
             )
             self._last_print = now
@@ -203,8 +271,7 @@ def run_live(cfg: dict, args: argparse.Namespace):
     session_name = f"dnb_{source_name}_{timestamp}"
 
     output_dir = Path(args.output_dir)
-    event_logger = EventLogger(output_dir, session_name)
-    status = StatusPrinter(event_logger)
+    event_logger = EventLogger(output_dir, session_name, **log_rotation(args))
 
     source = build_source_live(cfg, args.source)
     modules = build_modules(cfg)
@@ -232,6 +299,8 @@ def run_live(cfg: dict, args: argparse.Namespace):
         )
         pipeline.on_event("STIM", scheduler.on_stim_event)
 
+    status = StatusPrinter(event_logger, scheduler=scheduler, pipeline=pipeline)
+
     print()
     print("=" * 60)
     print("  DNB LIVE SESSION")
@@ -251,7 +320,7 @@ def run_live(cfg: dict, args: argparse.Namespace):
         # Set time mapping for scheduler
         t_start = time.perf_counter()
         if scheduler:
-            scheduler.set_time_offset(0.0, t_start)
+            scheduler.set_time_offset(0.0, scheduler.now())
             scheduler.start()
 
         original_handler = signal.getsignal(signal.SIGINT)
@@ -312,16 +381,36 @@ def run_offline(cfg: dict, args: argparse.Namespace):
         config=pipeline_config,
     )
 
-    event_logger = EventLogger(output_dir, f"dnb_offline_{timestamp}")
+    event_logger = EventLogger(output_dir, f"dnb_offline_{timestamp}", **log_rotation(args))
     pipeline.on_event(None, event_logger.log)
 
-    events = pipeline.run_offline()
+    csv_writer = None
+    if args.export_csv:
+        from dnb.engine.export import DetectionCsvWriter
+        csv_writer = DetectionCsvWriter(
+            output_dir / f"dnb_offline_{timestamp}_detections.csv", args.export_csv,
+        )
+
+    try:
+        events = pipeline.run_offline(result_callback=csv_writer)
+    finally:
+        if csv_writer is not None:
+            csv_writer.close()
     event_logger.save_npz()
     event_logger.close()
 
+    if args.check_state:
+        # Re-run the same module instances; any difference is a reset bug
+        from dnb.engine.consistency import check_rerun_consistency
+        problems = check_rerun_consistency(lambda: build_source(cfg), modules, pipeline_config)
+        print(f"State check: {'OK' if not problems else f'{len(problems)} divergences'}")
+
     detections = [e for e in events if e.event_type == EventType.SLOW_WAVE]
     stims = [e for e in events if e.event_type == EventType.STIM]
     print(f"\nOffline complete: {len(detections)} detections, {len(stims)} stims")
+    if pipeline.nonfinite_samples:
+        print(f"Non-finite input samples: {pipeline.nonfinite_samples} "
+              f"(nan_policy={pipeline_config.nan_policy})")
 
     # Print timing summary
     if stims and detections:
@@ -333,12 +422,18 @@ def run_offline(cfg: dict, args: argparse.Namespace):
         if delays:
             print(f"Detection\u2192Stim delay: {np.mean(delays):.0f} \u00b1 {np.std(delays):.0f} ms")
 
+    for m in modules:
+        errors = getattr(m, "prediction_errors", None)
+        if errors:
+            print(f"Prediction error '{m.id}': {np.mean(errors):+.1f} \u00b1 "
+                  f"{np.std(errors):.1f} ms (n={len(errors)})")
+
 
 # ── CLI ──────────────────────────────────────────────────────────────────
 
 def main():
     parser = argparse.ArgumentParser(description="DNB pipeline runner")
-    parser.add_argument("--config", "-c", required=True, help="YAML config file")
+    parser.add_argument("--config", "-c", required=True, help="YAML or JSON config file")
     parser.add_argument("--offline", action="store_true", help="Offline batch mode")
     parser.add_argument(
         "--source", "-s", choices=["nplay", "cerebus", "auto"],
@@ -348,26 +443,59 @@ def main():
     parser.add_argument("--channel", type=int, default=None, help="Hardware channel index")
     parser.add_argument("--output-dir", "-o", default="./output", help="Output directory")
     parser.add_argument("--verbose", "-v", action="store_true", help="Debug logging")
+    parser.add_argument(
+        "--log-max-mb", type=float, default=None,
+        help="Rotate the event log when it exceeds this size (MB)",
+    )
+    parser.add_argument(
+        "--log-max-files", type=int, default=5,
+        help="Rotated event logs to keep (with --log-max-mb)",
+    )
+    parser.add_argument(
+        "--check-state", action="store_true",
+        help="Offline: run twice with the same modules and flag any divergence",
+    )
+    parser.add_argument(
+        "--export-csv", nargs="+", metavar="ID:KEY", default=None,
+        help="Offline: write these detection values per chunk to a CSV",
+    )
+    parser.add_argument(
+        "--diff", metavar="OTHER", default=None,
+        help="Print differences between --config and OTHER, then exit",
+    )
     args = parser.parse_args()
 
     setup_logging(logging.DEBUG if args.verbose else logging.INFO)
     logger.info("DNB v%s", dnb.__version__)
 
-    cfg = load_config(args.config)
-
-    # Apply CLI overrides to the config dict BEFORE building anything
-    apply_overrides(cfg, args)
-
-    # Auto-detect offline mode if source is file
-    source_type = cfg.get("source", {}).get("type", "auto").lower()
-    if source_type == "file" and not args.offline:
-        logger.info("source.type is 'file' \u2014 switching to offline mode automatically")
-        args.offline = True
-
-    if args.offline:
-        run_offline(cfg, args)
-    else:
-        run_live(cfg, args)
+    try:
+        cfg = load_config(args.config)
+
+        if args.diff:
+            diffs = diff_configs(cfg, load_config(args.diff))
+            for d in diffs:
+                print(d)
+            if not diffs:
+                print("No differences.")
+            return
+
+        # Apply CLI overrides to the config dict BEFORE building anything
+        apply_overrides(cfg, args)
+        check_config(cfg)
+
+        # Auto-detect offline mode if source is file
+        source_type = cfg.get("source", {}).get("type", "auto").lower()
+        if source_type == "file" and not args.offline:
+            logger.info("source.type is 'file' \u2014 switching to offline mode automatically")
+            args.offline = True
+
+        if args.offline:
+            run_offline(cfg, args)
+        else:
+            run_live(cfg, args)
+    except DnbError as e:
+        logger.error("%s: %s", type(e).__name__, e)
+        sys.exit(1)
 
 
 if __name__ == "__main__":
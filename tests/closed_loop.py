@@ -0,0 +1,125 @@
+"""Regression guard: deterministic run of the whole closed-loop path.
+
+With the package installed (pip install -e .):
+    python tests/closed_loop.py            # compare against the golden file
+    python tests/closed_loop.py --record   # (re)write the golden file
+
+A fixed, seeded synthetic recording (slow waves in pink noise, plus one
+IED just before a slow wave) goes through
+wavelet → TWaveDetector → AmplitudeMonitor → StimTrigger offline. The
+resulting event sequence (type, pulse index, timestamp) is compared
+exactly against tests/closed_loop_golden.json, and the IED must
+suppress every detection/stim inside the inhibition cooldown — and
+actually suppress at least one candidate, so that check isn't vacuous.
+
+Offline processing has no wall-clock dependence, so the output is
+reproducible. Re-record only after an intended behaviour change, and
+review the diff of the golden file.
+"""
+
+import json
+import sys
+from pathlib import Path
+
+import numpy as np
+from dnb import ArraySource, EventType, Pipeline, PipelineConfig
+from dnb.modules import AmplitudeMonitor, StimTrigger, TWaveDetector, WaveletConvolution
+from dnb.validation.synthetic import generate_pink_noise, inject_ied, inject_slow_wave
+
+GOLDEN = Path(__file__).with_name("closed_loop_golden.json")
+
+SAMPLE_RATE = 500.0
+DURATION = 60.0
+SEED = 7
+SW_TIMES = [10.0, 18.0, 26.0, 34.0, 42.0, 50.0]
+IED_TIME = 41.6           # just before the slow wave at 42 s
+COOLDOWN_S = 2.0
+TOLERANCE_S = 1e-6
+
+
+def make_signal() -> np.ndarray:
+    n = int(DURATION * SAMPLE_RATE)
+    signal = 20.0 * generate_pink_noise(n, SAMPLE_RATE, seed=SEED)
+    for t in SW_TIMES:
+        inject_slow_wave(signal, t, SAMPLE_RATE, frequency=1.0, amplitude=200.0, n_cycles=3)
+    inject_ied(signal, IED_TIME, SAMPLE_RATE, amplitude=3000.0, seed=SEED)
+    return signal
+
+
+def run() -> tuple[list[dict], int]:
+    """Returns the event sequence and the number of inhibited candidates."""
+    trigger = StimTrigger(activation_detector_id="slow_wave",
+                          inhibition_detector_id="ied_monitor",
+                          n_pulses=1, backoff_s=2.0, inhibition_cooldown_s=COOLDOWN_S)
+    modules = [
+        WaveletConvolution(freq_min=0.5, freq_max=30.0, n_freqs=20, n_cycles_base=1.0),
+        TWaveDetector(id="slow_wave", freq_range=(0.5, 2.0), target_phase=0.0,
+                      warmup_chunks=20),
+        AmplitudeMonitor(id="ied_monitor", freq_range=(80.0, 120.0),
+                         adaptive_n_std=3.0, warmup_chunks=20),
+        trigger,
+    ]
+    config = PipelineConfig(sample_rate=SAMPLE_RATE, buffer_duration=10.0,
+                            chunk_duration=0.02)
+    pipeline = Pipeline(source=ArraySource(make_signal(), SAMPLE_RATE),
+                        modules=modules, config=config)
+    events = pipeline.run_offline()
+    sequence = [
+        {
+            "type": e.event_type.name,
+            "pulse_index": e.metadata.get("pulse_index"),
+            "timestamp": round(float(e.timestamp), 6),
+        }
+        for e in events
+    ]
+    return sequence, trigger.trigger_stats()["inhibited"]
+
+
+def check_inhibition(events: list[dict]) -> list[str]:
+    """No detection or stim may fall inside the IED's cooldown window."""
+    lo, hi = IED_TIME, IED_TIME + COOLDOWN_S
+    return [
+        f"{e['type']} at {e['timestamp']:.3f}s inside IED cooldown [{lo:.1f}, {hi:.1f}]s"
+        for e in events
+        if e["type"] in (EventType.SLOW_WAVE.name, EventType.STIM.name)
+        and lo <= e["timestamp"] <= hi
+    ]
+
+
+def compare(expected: list[dict], actual: list[dict]) -> list[str]:
+    problems = []
+    if len(expected) != len(actual):
+        problems.append(f"event count: expected {len(expected)}, got {len(actual)}")
+    for i, (a, b) in enumerate(zip(expected, actual)):
+        if (a["type"] != b["type"] or a["pulse_index"] != b["pulse_index"]
+                or abs(a["timestamp"] - b["timestamp"]) > TOLERANCE_S):
+            problems.append(f"event {i}: expected {a}, got {b}")
+    return problems
+
+
+def main() -> int:
+    events, inhibited = run()
+    print(f"{len(events)} events, {inhibited} inhibited candidates")
+    for e in events:
+        print(f"  {e['type']:<10} pulse={e['pulse_index']}  t={e['timestamp']:.6f}s")
+
+    problems = check_inhibition(events)
+    if inhibited == 0:
+        problems.append("the IED inhibited no candidate — inhibition check is vacuous")
+
+    if "--record" in sys.argv:
+        GOLDEN.write_text(json.dumps(events, indent=2) + "\n")
+        print(f"Recorded golden sequence to {GOLDEN}")
+    elif not GOLDEN.exists():
+        problems.append(f"no golden file — run with --record first ({GOLDEN})")
+    else:
+        problems += compare(json.loads(GOLDEN.read_text()), events)
+
+    for p in problems:
+        print(f"FAIL: {p}")
+    print("PASS" if not problems else f"{len(problems)} problem(s)")
+    return 1 if problems else 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
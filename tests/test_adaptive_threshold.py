@@ -0,0 +1,83 @@
+"""Behaviour check: adaptive amp_min converges on the target rate and respects its floor.
+
+The detector sees a stationary stream of 1 Hz "waves": each holds a
+random amplitude (uniform 50–250 µV) for 1 s, then drops out for 0.2 s,
+so every wave above amp_min is exactly one detection onset. At 50 waves
+per minute, a target of 10/min is met when 20 % of waves pass, i.e. at
+amp_min = 210 µV.
+
+Run:
+    pytest tests/test_adaptive_threshold.py
+"""
+
+import numpy as np
+
+from dnb.errors import ConfigError
+from dnb.modules import TWaveDetector
+from test_data import wavelet_now
+
+FS = 500.0
+CHUNK = 100                     # 0.2 s
+FREQS = [0.5, 1.0, 2.0]
+WAVE_CHUNKS, GAP_CHUNKS = 5, 1  # 1 s wave, 0.2 s gap
+
+
+def detector(**kwargs) -> TWaveDetector:
+    return TWaveDetector(
+        freq_range=(0.5, 2.0), prediction_limit_s=2.0, amp_min=75.0, amp_max=300.0,
+        hilo_ratio_max=None, template_threshold=None, warmup_chunks=0, **kwargs,
+    )
+
+
+def wave_amplitudes(n_waves: int, lo: float, hi: float, seed: int = 0) -> list[float]:
+    """Per-chunk 1 Hz amplitude: each wave's amplitude, then a silent gap."""
+    rng = np.random.default_rng(seed)
+    amps = []
+    for a in rng.uniform(lo, hi, n_waves):
+        amps += [float(a)] * WAVE_CHUNKS + [0.0] * GAP_CHUNKS
+    return amps
+
+
+def run(det: TWaveDetector, amplitudes: list[float]):
+    """Returns per-chunk (time, effective amp_min, active)."""
+    out = []
+    for i, amp in enumerate(amplitudes):
+        amps = [amp if f == 1.0 else 1.0 for f in FREQS]
+        result = det.process(wavelet_now(FREQS, amps, [1.0] * len(FREQS), FS, i * CHUNK, CHUNK))
+        out.append((float(result.chunk.timestamps[-1]), det.effective_amp_min,
+                    result.detections[det.id]["active"]))
+    return out
+
+
+def test_converges_to_target_rate():
+    det = detector(target_rate_per_min=10.0, rate_window_s=120.0, adapt_gain=0.001)
+    trace = run(det, wave_amplitudes(50 * 40, 50.0, 250.0))     # 40 min
+    t_end = trace[-1][0]
+    last = [(t, th, a) for t, th, a in trace if t > t_end - 600.0]
+    mean_threshold = float(np.mean([th for _, th, _ in last]))
+    assert abs(mean_threshold - 210.0) < 15.0, mean_threshold
+    onsets = sum(1 for (_, _, a0), (_, _, a1) in zip(last, last[1:]) if a1 and not a0)
+    assert 70 <= onsets <= 130, f"{onsets} detections in the last 10 min"
+    assert all(th <= 300.0 for _, th, _ in trace)
+
+
+def test_quiet_stream_stops_at_default_floor():
+    det = detector(target_rate_per_min=10.0, rate_window_s=10.0, adapt_gain=0.02)
+    trace = run(det, [20.0] * int(300 / 0.2))                   # 5 min, nothing passes
+    thresholds = [th for _, th, _ in trace]
+    assert min(thresholds) == 37.5, min(thresholds)              # amp_min / 2
+    assert thresholds[-1] == 37.5, thresholds[-1]
+    assert not any(a for _, _, a in trace)
+
+
+def test_configured_floor():
+    det = detector(target_rate_per_min=10.0, rate_window_s=10.0, adapt_gain=0.02,
+                   amp_min_floor=60.0)
+    trace = run(det, [20.0] * int(300 / 0.2))
+    assert min(th for _, th, _ in trace) == 60.0
+    for bad in (0.0, -1.0, 80.0):
+        try:
+            detector(target_rate_per_min=10.0, amp_min_floor=bad)
+        except ConfigError:
+            continue
+        raise AssertionError(f"amp_min_floor={bad} accepted with amp_min=75")
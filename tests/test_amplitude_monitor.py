@@ -0,0 +1,67 @@
+"""Behaviour check: AmplitudeMonitor freezes its baseline after the learning period.
+
+White noise in 0.1 s chunks with freeze_statistics_after_s = 2: the
+baseline stops taking chunks once 2 s have been seen, so a later burst
+can't inflate it. After reset() a rerun, even from a later start time,
+freezes at the same chunk and reports the same values.
+
+Run:
+    pytest tests/test_amplitude_monitor.py
+"""
+
+import numpy as np
+
+from dnb.modules import AmplitudeMonitor
+from dnb.modules.base import ProcessResult
+from test_data import chunk_at
+
+FS = 1000.0
+CHUNK = 100                     # 0.1 s
+N_CHUNKS = 40
+FREEZE_AT = 20                  # first chunk ending at or after t_first + 2 s
+BURST = range(30, 33)
+
+
+def signal(seed: int = 0) -> np.ndarray:
+    x = np.random.default_rng(seed).standard_normal(N_CHUNKS * CHUNK)
+    for k in BURST:
+        x[k * CHUNK:(k + 1) * CHUNK] *= 20.0
+    return x
+
+
+def monitor() -> AmplitudeMonitor:
+    return AmplitudeMonitor(warmup_chunks=5, freeze_statistics_after_s=2.0)
+
+
+def run(mon: AmplitudeMonitor, x: np.ndarray, start_index: int = 0) -> list[tuple]:
+    """Per chunk: (power, z_score, active, frozen, baseline count)."""
+    trace = []
+    for k in range(N_CHUNKS):
+        chunk = chunk_at(x[k * CHUNK:(k + 1) * CHUNK], FS, start_index + k * CHUNK)
+        det = mon.process(ProcessResult(chunk=chunk)).detections[mon.id]
+        trace.append((det["power"], det.get("z_score"), det["active"],
+                      mon.statistics_frozen, mon._stats.count))
+    return trace
+
+
+def test_baseline_freezes_after_learning_period():
+    trace = run(monitor(), signal())
+    frozen = [f for _, _, _, f, _ in trace]
+    assert frozen == [False] * FREEZE_AT + [True] * (N_CHUNKS - FREEZE_AT), frozen
+    counts = [n for _, _, _, _, n in trace]
+    # Warmup chunks always feed the baseline; nothing does once frozen
+    assert counts[:5] == [1, 2, 3, 4, 5], counts
+    assert len(set(counts[FREEZE_AT - 1:])) == 1, counts
+    assert all(trace[k][2] for k in BURST), [trace[k] for k in BURST]
+
+
+def test_reset_then_rerun_matches():
+    mon = monitor()
+    x = signal()
+    first = run(mon, x)
+    mon.reset()
+    assert not mon.statistics_frozen
+    assert run(mon, x) == first
+    mon.reset()
+    # A later clock: the learning period counts from the new first sample
+    assert run(mon, x, start_index=int(60 * FS)) == first
@@ -0,0 +1,113 @@
+"""Behaviour check: burst detectors report bursts within their duration bounds.
+
+Tone bursts are embedded in seeded white noise; the detector must report
+one event per in-bounds burst and ignore bursts that are too short or
+too long, even though it sees them (goes active).
+
+Run:
+    pytest tests/test_burst_detectors.py
+"""
+
+from math import pi
+
+import numpy as np
+
+from dnb.core.types import EventType
+from dnb.modules import RippleDetector, SpindleDetector
+from dnb.modules.base import ProcessResult
+from test_data import chunk_at
+
+
+def noise_with_bursts(fs: float, duration_s: float, freq: float, amplitude: float,
+                      bursts: list[tuple[float, float]], seed: int = 0) -> np.ndarray:
+    """White noise (std 1) plus constant-amplitude tone bursts at (start_s, length_s)."""
+    x = np.random.default_rng(seed).standard_normal(int(duration_s * fs))
+    t = np.arange(x.shape[0]) / fs
+    for start, length in bursts:
+        inside = (t >= start) & (t < start + length)
+        x[inside] += amplitude * np.sin(2 * pi * freq * t[inside])
+    return x
+
+
+def run(detector, signal: np.ndarray, fs: float, chunk: int):
+    """Returns (events, per-chunk (end time, active) pairs).
+
+    Chunks must be short next to the bursts for `active` to catch them.
+    """
+    events, active = [], []
+    for start in range(0, signal.shape[0], chunk):
+        result = detector.process(ProcessResult(chunk=chunk_at(signal[start:start + chunk], fs, start)))
+        events += result.events
+        active.append((float(result.chunk.timestamps[-1]), result.detections[detector.id]["active"]))
+    return events, active
+
+
+def seen_during(active: list[tuple[float, bool]], start: float, end: float) -> bool:
+    return any(a for t, a in active if start <= t <= end)
+
+
+RIPPLE_FS = 2000.0
+
+
+def ripple_run(bursts: list[tuple[float, float]]):
+    signal = noise_with_bursts(RIPPLE_FS, 6.0, 150.0, 10.0, bursts)
+    # 5 ms chunks; 0.5 s of warmup for the noise baseline
+    return run(RippleDetector(warmup_chunks=100), signal, RIPPLE_FS, chunk=10)
+
+
+def test_ripple_in_bounds_is_detected():
+    events, _ = ripple_run([(3.0, 0.1)])
+    assert len(events) == 1, events
+    e = events[0]
+    assert e.event_type == EventType.RIPPLE and e.metadata["detector_id"] == "ripple", e
+    assert abs(e.timestamp - 3.0) < 0.01, e.timestamp
+    assert 85.0 <= e.metadata["duration_ms"] <= 115.0, e.metadata
+    assert abs(e.duration * 1000 - e.metadata["duration_ms"]) < 1e-9
+
+
+def test_ripple_too_short_is_ignored():
+    events, active = ripple_run([(3.0, 0.008)])
+    assert seen_during(active, 3.0, 3.1), "burst never went above threshold"
+    assert events == [], events
+
+
+def test_ripple_too_long_is_ignored():
+    events, active = ripple_run([(3.0, 0.4)])
+    assert seen_during(active, 3.0, 3.5), "burst never went above threshold"
+    assert events == [], events
+
+
+SPINDLE_FS = 500.0
+
+
+def spindle_run(bursts: list[tuple[float, float]]):
+    signal = noise_with_bursts(SPINDLE_FS, 12.0, 13.0, 5.0, bursts)
+    # z > 4 keeps narrowband noise excursions (long-lived at 5 Hz
+    # bandwidth) from ever lasting 0.5 s; 20 ms chunks, 1 s warmup
+    det = SpindleDetector(z_threshold=4.0, warmup_chunks=50)
+    return run(det, signal, SPINDLE_FS, chunk=10)
+
+
+def test_spindle_detected_and_brief_blip_ignored():
+    events, active = spindle_run([(5.0, 1.0), (9.0, 0.2)])
+    assert len(events) == 1, events
+    e = events[0]
+    assert e.event_type == EventType.SPINDLE, e
+    assert abs(e.timestamp - 5.0) < 0.1, e.timestamp
+    assert 900.0 <= e.metadata["duration_ms"] <= 1300.0, e.metadata
+    assert seen_during(active, 9.0, 9.5), "blip never went above threshold"
+
+
+def test_spindle_too_long_reports_duration_but_no_event():
+    det = SpindleDetector(z_threshold=4.0, warmup_chunks=50)
+    signal = noise_with_bursts(SPINDLE_FS, 8.0, 13.0, 5.0, [(3.0, 2.5)])
+    events, durations = [], []
+    for start in range(0, signal.shape[0], 10):
+        result = det.process(ProcessResult(chunk=chunk_at(signal[start:start + 10], SPINDLE_FS, start)))
+        events += result.events
+        d = result.detections[det.id].get("burst_duration_ms")
+        if d is not None and 3.0 <= result.chunk.timestamps[-1] <= 6.0:
+            durations.append(d)
+    assert durations and durations == sorted(durations), "ongoing duration must grow"
+    assert durations[-1] > 2000.0, durations[-1]
+    assert events == [], events
@@ -0,0 +1,75 @@
+"""Behaviour check: TWaveDetector's candidate log records rejected candidates.
+
+Run:
+    pytest tests/test_candidate_log.py
+"""
+
+from dnb.modules import TWaveDetector
+from test_data import cosine_buffer, wavelet_now
+
+FS = 500.0
+CHUNK = 10
+FREQS = [0.5, 1.0, 2.0, 20.0]
+PHASE = 1.0  # rad; next 0-phase target is (2π − 1) / 2π ≈ 0.84 s away at 1 Hz
+
+
+def detector(**kwargs) -> TWaveDetector:
+    defaults = dict(
+        freq_range=(0.5, 2.0), prediction_limit_s=1.0, amp_min=75.0, amp_max=300.0,
+        hilo_ratio_max=None, template_threshold=None, warmup_chunks=0,
+        record_candidates=True,
+    )
+    return TWaveDetector(**{**defaults, **kwargs})
+
+
+def feed(det: TWaveDetector, n_chunks: int, amplitude: float = 100.0) -> None:
+    """Chunks whose wavelet and buffer both show a clean 1 Hz cosine."""
+    amps = [1.0, amplitude, 1.0, 5.0]
+    for i in range(n_chunks):
+        result = wavelet_now(FREQS, amps, [PHASE] * len(FREQS), FS, i * CHUNK, CHUNK)
+        result.ring_buffer = cosine_buffer(1.0, PHASE, FS, 2.0, amplitude)
+        det.process(result)
+
+
+def test_subthreshold_candidate_logged_with_scores():
+    det = detector(template_threshold=0.9)
+    feed(det, 1)
+    log = det.candidate_log
+    assert len(log) == 1, log
+    entry = log[0]
+    assert entry["passed"] is False and entry["reject_reason"] == "template", entry
+    # z-scored sinusoid · unit cosine averages to 1/√2 over whole periods
+    assert abs(entry["template_score"] - 0.7071) < 0.01, entry
+    assert abs(entry["hilo_ratio"] - 5.0 / 34.0) < 1e-9, entry
+    assert abs(entry["amplitude"] - 100.0) < 1e-9, entry
+    assert entry["timestamp"] == (CHUNK - 1) / FS, entry
+
+
+def test_scores_logged_for_disabled_checks():
+    det = detector(amp_min=150.0)
+    feed(det, 1)
+    entry = det.candidate_log[0]
+    assert entry["passed"] is False and entry["reject_reason"] == "amplitude", entry
+    # Neither threshold is set, but both scores are still computed
+    assert entry["template_score"] is not None and entry["hilo_ratio"] is not None, entry
+
+
+def test_passing_candidate_logged():
+    det = detector(template_threshold=0.5)
+    feed(det, 1)
+    entry = det.candidate_log[0]
+    assert entry["passed"] is True and entry["reject_reason"] is None, entry
+
+
+def test_nothing_logged_beyond_prediction_limit():
+    det = detector(prediction_limit_s=0.15)
+    feed(det, 3)
+    assert det.candidate_log == []
+
+
+def test_log_keeps_only_candidate_log_length():
+    det = detector(candidate_log_length=3)
+    feed(det, 5)
+    log = det.candidate_log
+    assert len(log) == 3, log
+    assert [e["timestamp"] for e in log] == [((i + 1) * CHUNK - 1) / FS for i in (2, 3, 4)], log
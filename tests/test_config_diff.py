@@ -0,0 +1,51 @@
+"""Behaviour check: diff_configs reports nested changes by dotted path.
+
+Run:
+    pytest tests/test_config_diff.py
+"""
+
+import copy
+
+from dnb.config import diff_configs
+
+BASE = {
+    "target_wave": {"amp_min": 75.0, "freq_range": [0.5, 2.0]},
+    "filters": [
+        {"type": "notch", "freq": 50.0, "q": 30.0},
+        {"type": "comb_notch", "base_freq": 50.0, "n_harmonics": 3},
+    ],
+}
+
+
+def test_identical_configs_have_no_diff():
+    assert diff_configs(BASE, copy.deepcopy(BASE)) == []
+
+
+def test_nested_dict_change_has_dotted_path():
+    other = copy.deepcopy(BASE)
+    other["target_wave"]["amp_min"] = 60.0
+    diffs = diff_configs(BASE, other)
+    assert [str(d) for d in diffs] == ["~ target_wave.amp_min: 75.0 -> 60.0"], diffs
+
+
+def test_list_entry_change_reports_index_and_key():
+    other = copy.deepcopy(BASE)
+    other["filters"][1]["n_harmonics"] = 5
+    diffs = diff_configs(BASE, other)
+    assert len(diffs) == 1, diffs
+    d = diffs[0]
+    assert (d.kind, d.path, d.old, d.new) == ("changed", "filters[1].n_harmonics", 3, 5), d
+
+
+def test_list_length_change_reports_added_entry():
+    other = copy.deepcopy(BASE)
+    other["filters"].append({"type": "notch", "freq": 60.0})
+    diffs = diff_configs(BASE, other)
+    assert [(d.kind, d.path) for d in diffs] == [("added", "filters[2]")], diffs
+
+
+def test_removed_section_reported_once():
+    other = copy.deepcopy(BASE)
+    del other["filters"]
+    diffs = diff_configs(BASE, other)
+    assert [(d.kind, d.path) for d in diffs] == [("removed", "filters")], diffs
@@ -0,0 +1,53 @@
+"""Behaviour check: validate_config/check_config report every problem at once.
+
+Run:
+    pytest tests/test_config_validation.py
+"""
+
+from dnb.config import build_source, check_config, validate_config
+from dnb.errors import ConfigError
+
+VALID = {
+    "source": {"type": "file", "path": "recording.npz"},
+    "pipeline": {"sample_rate": 500.0},
+    "target_wave": {"freq_range": [0.5, 2.0]},
+    "trigger": {"activation_detector_id": "slow_wave"},
+}
+
+
+def with_changes(**sections) -> dict:
+    return {**VALID, **{k: {**VALID.get(k, {}), **v} for k, v in sections.items()}}
+
+
+def test_valid_config_has_no_problems():
+    assert validate_config(VALID) == []
+    check_config(VALID)
+
+
+def test_three_errors_in_one_message():
+    cfg = with_changes(
+        source={"type": "tcp"},
+        target_wave={"freq_range": [0.5, 300.0]},
+        trigger={"activation_detector_id": "slow_wav"},
+    )
+    try:
+        check_config(cfg)
+    except ConfigError as e:
+        msg = str(e)
+    else:
+        raise AssertionError("check_config accepted an invalid config")
+    assert msg.startswith("3 config problem(s):"), msg
+    assert "source.type: unknown type 'tcp'" in msg, msg
+    assert "target_wave.freq_range: 300 Hz is at or above Nyquist" in msg, msg
+    assert "trigger.activation_detector_id: no detector with id 'slow_wav'" in msg, msg
+
+
+def test_auto_source_is_accepted_and_built():
+    cfg = with_changes(source={"type": "auto"})
+    assert validate_config(cfg) == []
+    # Without pycbsdk neither live source exists; that is a ConfigError,
+    # never a silently unbuilt source type
+    try:
+        assert build_source(cfg) is not None
+    except ConfigError as e:
+        assert "auto" in str(e), e
@@ -8,6 +8,9 @@ from pathlib import Path
 
 import numpy as np
 
+from dnb.core.ring_buffer import RingBuffer
+from dnb.core.types import DataChunk, WaveletResult
+from dnb.modules.base import ProcessResult
 from dnb.validation.synthetic import (
     generate_pink_noise, inject_slow_wave, inject_ied, save_synthetic,
 )
@@ -106,4 +109,37 @@ def _place_events(
             if avoid is None or all(abs(t - a) > avoid_radius for a in avoid):
                 times.append(t)
     times.sort()
-    return times
\ No newline at end of file
+    return times
+
+
+def chunk_at(samples, sample_rate: float, start_index: int = 0) -> DataChunk:
+    """DataChunk of samples whose first sample is sample start_index of the stream."""
+    samples = np.asarray(samples, dtype=np.float64)
+    return DataChunk(
+        samples=samples,
+        timestamps=(start_index + np.arange(samples.shape[0])) / sample_rate,
+        channel_id=0, sample_rate=sample_rate,
+    )
+
+
+def wavelet_now(freqs, amplitudes, phases, sample_rate: float, start_index: int = 0,
+                n_samples: int = 10) -> ProcessResult:
+    """Settled ProcessResult whose wavelet output at the latest sample has
+    the given amplitude and phase per frequency (earlier samples are zero)."""
+    chunk = chunk_at(np.zeros(n_samples), sample_rate, start_index)
+    analytic = np.zeros((len(freqs), n_samples), dtype=np.complex128)
+    analytic[:, -1] = np.asarray(amplitudes) * np.exp(1j * np.asarray(phases))
+    wavelet = WaveletResult(analytic=analytic, frequencies=np.asarray(freqs, dtype=np.float64),
+                            chunk=chunk)
+    return ProcessResult(chunk=chunk, wavelet=wavelet, wavelet_settled=True)
+
+
+def cosine_buffer(freq: float, phase_now: float, sample_rate: float,
+                  duration_s: float, amplitude: float = 100.0) -> RingBuffer:
+    """RingBuffer holding duration_s of amplitude·cos at freq, ending so
+    that its phase would reach phase_now on the next sample."""
+    n = int(duration_s * sample_rate)
+    t = (np.arange(n) - n) / sample_rate
+    buf = RingBuffer(n)
+    buf.write(amplitude * np.cos(2 * pi * freq * t + phase_now))
+    return buf
\ No newline at end of file
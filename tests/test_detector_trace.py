@@ -0,0 +1,62 @@
+"""Behaviour check: TWaveDetector's debug trace follows its decisions.
+
+Run:
+    pytest tests/test_detector_trace.py
+"""
+
+from dnb.modules import TWaveDetector
+from test_data import wavelet_now
+
+FS = 500.0
+CHUNK = 10
+FREQS = [0.5, 1.0, 2.0]
+
+
+def detector(**kwargs) -> TWaveDetector:
+    return TWaveDetector(
+        freq_range=(0.5, 2.0), prediction_limit_s=10.0, amp_min=75.0, amp_max=300.0,
+        hilo_ratio_max=None, template_threshold=None, warmup_chunks=0, **kwargs,
+    )
+
+
+def feed(det: TWaveDetector, amplitudes: list[float]) -> list[bool]:
+    """One chunk per amplitude (at 1 Hz); returns the detector's active flag per chunk."""
+    active = []
+    for i, amp in enumerate(amplitudes):
+        amps = [amp if f == 1.0 else 1.0 for f in FREQS]
+        result = det.process(wavelet_now(FREQS, amps, [1.0] * len(FREQS), FS, i * CHUNK, CHUNK))
+        active.append(result.detections[det.id]["active"])
+    return active
+
+
+def test_trace_matches_active_state():
+    det = detector(debug=True)
+    amplitudes = [100.0, 50.0, 50.0, 150.0, 400.0, 100.0]
+    active = feed(det, amplitudes)
+    assert active == [True, False, False, True, False, True], active
+
+    trace = det.trace
+    assert [e["active"] for e in trace] == active, trace
+    assert [e.get("reject_reason") for e in trace] == [
+        None, "amplitude", "amplitude", None, "amplitude", None,
+    ], trace
+    assert all(abs(e["amplitude"] - a) < 1e-9 for e, a in zip(trace, amplitudes)), trace
+    # One entry per chunk, stamped with the chunk's last sample
+    expected_t = [((i + 1) * CHUNK - 1) / FS for i in range(len(amplitudes))]
+    assert [e["timestamp"] for e in trace] == expected_t, trace
+    assert all("candidates" not in e for e in trace)
+
+
+def test_trace_keeps_only_trace_length_chunks():
+    det = detector(debug=True, trace_length=4)
+    active = feed(det, [100.0, 50.0] * 6)
+    trace = det.trace
+    assert len(trace) == 4, len(trace)
+    assert [e["active"] for e in trace] == active[-4:], trace
+    assert trace[-1]["timestamp"] == (12 * CHUNK - 1) / FS, trace[-1]
+
+
+def test_no_trace_without_debug():
+    det = detector()
+    feed(det, [100.0, 50.0])
+    assert det.trace == []
@@ -0,0 +1,119 @@
+"""Behaviour check: EDF channels round-trip through read_edf_channel/EdfSource.
+
+A small EDF+ file is written here (two data signals at different rates
+and units, with an annotation signal between them) and read back.
+
+Run:
+    pytest tests/test_edf.py
+"""
+
+import tempfile
+from math import pi
+from pathlib import Path
+
+import numpy as np
+
+from dnb.core.types import PipelineConfig
+from dnb.errors import ValidationError
+from dnb.sources import EdfSource, read_edf_channel, read_edf_header
+
+N_RECORDS = 4
+# label, unit, samples per 1 s record; physical range ±3276.8 over int16 → 0.1 unit per step
+SIGNALS = [("EEG C3", "uV", 256), ("EDF Annotations", "", 30), ("EEG C4", "mV", 128)]
+
+
+def write_edf(path: Path, data: list[np.ndarray]) -> None:
+    """Write data (int16 digital values per signal) as an EDF+ file."""
+    ns = len(SIGNALS)
+
+    def fields(values, width: int) -> bytes:
+        return b"".join(str(v).ljust(width)[:width].encode("latin-1") for v in values)
+
+    header = (
+        fields(["0"], 8) + fields(["X X X X"], 80) + fields(["Startdate X X X X"], 80)
+        + fields(["01.01.26", "00.00.00", 256 * (ns + 1)], 8) + fields(["EDF+C"], 44)
+        + fields([N_RECORDS, 1], 8) + fields([ns], 4)
+    )
+    header += fields([s[0] for s in SIGNALS], 16) + fields([""] * ns, 80)
+    header += fields([s[1] for s in SIGNALS], 8)
+    header += fields(["-3276.8"] * ns, 8) + fields(["3276.7"] * ns, 8)
+    header += fields(["-32768"] * ns, 8) + fields(["32767"] * ns, 8)
+    header += fields([""] * ns, 80) + fields([s[2] for s in SIGNALS], 8) + fields([""] * ns, 32)
+    assert len(header) == 256 * (ns + 1)
+
+    records = [
+        np.concatenate([d[r * spr:(r + 1) * spr] for d, (_, _, spr) in zip(data, SIGNALS)])
+        for r in range(N_RECORDS)
+    ]
+    path.write_bytes(header + np.concatenate(records).astype("<i2").tobytes())
+
+
+def sine(fs: float, freq: float, amplitude: float) -> np.ndarray:
+    return amplitude * np.sin(2 * pi * freq * np.arange(int(N_RECORDS * fs)) / fs)
+
+
+def with_edf(test) -> None:
+    """Run test(path, c3_uV, c4_mV) against a freshly written file."""
+    c3 = sine(256.0, 10.0, 500.0)
+    c4 = sine(128.0, 2.0, 1.5)
+    digital = [np.round(c3 * 10), np.zeros(N_RECORDS * 30), np.round(c4 * 10)]
+    with tempfile.TemporaryDirectory() as d:
+        path = Path(d) / "small.edf"
+        write_edf(path, digital)
+        test(path, c3, c4)
+
+
+def test_header_lists_data_channels_and_rates():
+    def check(path, c3, c4):
+        h = read_edf_header(path)
+        assert h.n_records == N_RECORDS, h.n_records
+        assert h.channel_labels == ["EEG C3", "EEG C4"], h.channel_labels
+        assert list(h.sample_rates) == [256.0, 30.0, 128.0], h.sample_rates
+    with_edf(check)
+
+
+def test_round_trip_by_label_and_index():
+    def check(path, c3, c4):
+        samples, fs = read_edf_channel(path, "EEG C3")
+        assert fs == 256.0 and samples.shape == c3.shape, (fs, samples.shape)
+        assert np.max(np.abs(samples - c3)) <= 0.05 + 1e-9, np.max(np.abs(samples - c3))
+        # Index 1 is the second *data* channel — the annotation signal is skipped;
+        # mV are converted to µV
+        samples, fs = read_edf_channel(path, 1)
+        assert fs == 128.0 and samples.shape == c4.shape, (fs, samples.shape)
+        assert np.max(np.abs(samples - c4 * 1000)) <= 50.0 + 1e-6, np.max(np.abs(samples - c4 * 1000))
+    with_edf(check)
+
+
+def test_out_of_range_channel_is_rejected():
+    def check(path, c3, c4):
+        for channel in (2, 5, -1, "EEG Fp1", "EDF Annotations"):
+            try:
+                read_edf_channel(path, channel)
+            except ValidationError as e:
+                assert "EEG C3, EEG C4" in str(e), e
+                continue
+            raise AssertionError(f"channel {channel!r} accepted")
+    with_edf(check)
+
+
+def test_edf_source_streams_the_channel():
+    def check(path, c3, c4):
+        source = EdfSource(path)
+        source.connect(PipelineConfig(channel_id=0, chunk_duration=0.5))
+        assert source.resolved_config.sample_rate == 256.0
+        chunks = []
+        while (chunk := source.read_chunk()) is not None:
+            chunks.append(chunk.samples)
+        source.close()
+        samples = np.concatenate(chunks)
+        assert samples.shape == c3.shape, samples.shape
+        assert np.max(np.abs(samples - c3)) <= 0.05 + 1e-9
+
+        source = EdfSource(path)
+        try:
+            source.connect(PipelineConfig(channel_id=2))
+        except ValidationError:
+            return
+        raise AssertionError("channel_id 2 accepted with two data channels")
+    with_edf(check)
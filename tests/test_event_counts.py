@@ -0,0 +1,86 @@
+"""Behaviour check: Pipeline.event_counts agrees with the events a run returns.
+
+Slow waves in pink noise go through wavelet → TWaveDetector →
+StimTrigger offline; the per-trigger STIM counter must equal the STIM
+events returned, and the per-detector count the detector's onsets.
+
+Run:
+    pytest tests/test_event_counts.py
+"""
+
+import numpy as np
+
+from dnb import ArraySource, EventType, Pipeline, PipelineConfig
+from dnb.modules import StimTrigger, TWaveDetector, WaveletConvolution
+from dnb.validation.synthetic import generate_pink_noise, inject_slow_wave
+
+FS = 500.0
+DURATION = 60.0
+
+
+def make_signal(seed: int = 3) -> np.ndarray:
+    signal = 20.0 * generate_pink_noise(int(DURATION * FS), FS, seed=seed)
+    for t in np.arange(8.0, DURATION - 4.0, 6.0):
+        inject_slow_wave(signal, float(t), FS, frequency=1.0, amplitude=200.0, n_cycles=3)
+    return signal
+
+
+def run(**trigger_kwargs):
+    """Returns (pipeline, events, detector onsets counted from the results)."""
+    pipeline = Pipeline(
+        source=ArraySource(make_signal(), FS),
+        modules=[
+            WaveletConvolution(freq_min=0.5, freq_max=30.0, n_freqs=20),
+            TWaveDetector(id="slow_wave", freq_range=(0.5, 2.0), warmup_chunks=20),
+            StimTrigger(activation_detector_id="slow_wave", inhibition_detector_id=None,
+                        n_pulses=2, backoff_s=2.0, **trigger_kwargs),
+        ],
+        config=PipelineConfig(sample_rate=FS, buffer_duration=10.0, chunk_duration=0.05),
+    )
+    onsets, was_active = 0, False
+
+    def count_onsets(result):
+        nonlocal onsets, was_active
+        active = result.detections["slow_wave"]["active"]
+        onsets += active and not was_active
+        was_active = active
+
+    events = pipeline.run_offline(result_callback=count_onsets)
+    return pipeline, events, onsets
+
+
+def of_type(events, event_type: EventType) -> list:
+    return [e for e in events if e.event_type == event_type]
+
+
+def test_trigger_counter_matches_stim_events():
+    pipeline, events, _ = run()
+    counts = pipeline.event_counts()
+    stims = of_type(events, EventType.STIM)
+    assert stims, "no stims — nothing to count"
+    assert counts["STIM"] == counts["STIM:trigger"] == len(stims), (counts, len(stims))
+    assert pipeline.trigger_stats()["trigger"]["stims"] == len(stims)
+    assert counts["SLOW_WAVE:trigger"] == len(of_type(events, EventType.SLOW_WAVE)), counts
+    assert all(e.metadata["trigger_id"] == "trigger" for e in stims)
+
+
+def test_detector_onsets_counted_per_detector():
+    pipeline, events, onsets = run()
+    counts = pipeline.event_counts()
+    assert counts["DETECTION:slow_wave"] == onsets, (counts, onsets)
+    # Backoff drops some active runs, never adds any
+    assert onsets >= len(of_type(events, EventType.SLOW_WAVE)) > 0
+
+
+def test_counts_keyed_by_trigger_id():
+    pipeline, events, _ = run(id="closed_loop")
+    counts = pipeline.event_counts()
+    n = len(of_type(events, EventType.STIM))
+    assert counts["STIM:closed_loop"] == n and "STIM:trigger" not in counts, counts
+    assert list(pipeline.trigger_stats()) == ["closed_loop"]
+
+
+def test_reset_clears_counts():
+    pipeline, _, _ = run()
+    pipeline.reset()
+    assert pipeline.event_counts() == {}
@@ -0,0 +1,182 @@
+"""Behaviour check: chunk filters attenuate what they target and pass the rest.
+
+Gains are measured on the streamed output (chunk by chunk, state carried
+over), after start-up transients have decayed.
+
+Run:
+    pytest tests/test_filters.py
+"""
+
+from math import pi
+
+import numpy as np
+from scipy.signal import butter, iirnotch, lfilter
+
+from dnb.errors import ConfigError
+from dnb.modules import (
+    BaselineRemovalFilter, CombNotchFilter, IirFilter, MovingAverageFilter, NotchFilter,
+)
+from dnb.modules.base import ProcessResult
+from test_data import chunk_at
+
+FS = 1000.0
+CHUNK = 100
+
+
+def stream(filt, signal: np.ndarray, fs: float = FS, chunk: int = CHUNK) -> np.ndarray:
+    """Run signal through filt chunk by chunk; return the concatenated output."""
+    filt.reset()
+    out = []
+    for start in range(0, signal.shape[0], chunk):
+        result = filt.process(ProcessResult(chunk=chunk_at(signal[start:start + chunk], fs, start)))
+        out.append(result.chunk.samples)
+    return np.concatenate(out)
+
+
+def gain(filt, freq: float, fs: float = FS, duration_s: float = 6.0) -> float:
+    """Steady-state amplitude gain at freq: output/input RMS over the last 2 s."""
+    t = np.arange(int(duration_s * fs)) / fs
+    x = np.sin(2 * pi * freq * t)
+    y = stream(filt, x, fs)
+    tail = int(2.0 * fs)
+    return float(np.sqrt(np.mean(y[-tail:] ** 2)) / np.sqrt(np.mean(x[-tail:] ** 2)))
+
+
+def test_comb_notch_removes_line_noise_and_harmonics():
+    f = CombNotchFilter(base_freq=50.0, n_harmonics=3, q=30.0)
+    for freq in (50.0, 100.0, 150.0):
+        g = gain(f, freq)
+        assert g < 0.01, f"{freq} Hz gain {g:.4f}"
+
+
+def test_comb_notch_passes_other_frequencies():
+    f = CombNotchFilter(base_freq=50.0, n_harmonics=3, q=30.0)
+    for freq in (1.0, 10.0, 75.0, 200.0):
+        g = gain(f, freq)
+        assert g > 0.95, f"{freq} Hz gain {g:.4f}"
+
+
+def test_comb_notch_skips_harmonics_above_nyquist():
+    f = CombNotchFilter(base_freq=50.0, n_harmonics=3)
+    assert f.coefficients(250.0).shape == (2, 6)   # 50, 100 Hz; 150 ≥ 125 Hz
+    assert f.coefficients(1000.0).shape == (3, 6)
+
+
+def test_notch_removes_its_frequency_only():
+    f = NotchFilter(freq=60.0, q=30.0)
+    assert gain(f, 60.0) < 0.01
+    for freq in (1.0, 10.0, 50.0, 120.0):
+        g = gain(f, freq)
+        assert g > 0.95, f"{freq} Hz gain {g:.4f}"
+
+
+def test_notch_above_nyquist_passes_through():
+    f = NotchFilter(freq=60.0)
+    x = np.random.default_rng(1).standard_normal(1000)
+    assert f.coefficients(100.0) is None
+    assert np.array_equal(stream(f, x, fs=100.0), x)
+
+
+def test_streaming_matches_one_pass():
+    rng = np.random.default_rng(0)
+    x = rng.standard_normal(5000)
+    f = CombNotchFilter(base_freq=50.0)
+    chunked = stream(f, x, chunk=37)
+    whole = stream(f, x, chunk=x.shape[0])
+    assert np.allclose(chunked, whole, atol=1e-10), np.max(np.abs(chunked - whole))
+
+
+def test_iir_matches_equivalent_notch():
+    b, a = iirnotch(60.0, 30.0, fs=FS)
+    x = np.random.default_rng(2).standard_normal(3000)
+    iir = stream(IirFilter(b=list(b), a=list(a), design_rate=FS), x)
+    notch = stream(NotchFilter(freq=60.0, q=30.0), x)
+    assert np.allclose(iir, notch, atol=1e-10), np.max(np.abs(iir - notch))
+
+
+def test_iir_butterworth_matches_lfilter():
+    b, a = butter(4, 40.0, fs=FS)
+    f = IirFilter(b=list(b), a=list(a), design_rate=FS)
+    x = np.random.default_rng(3).standard_normal(3000)
+    assert np.allclose(stream(f, x, chunk=37), lfilter(b, a, x), atol=1e-9)
+    assert gain(f, 5.0) > 0.99
+    assert gain(f, 200.0) < 0.01
+    # a is normalized internally: scaling b and a together changes nothing
+    scaled = IirFilter(b=list(3 * b), a=list(3 * a), design_rate=FS)
+    assert np.allclose(stream(scaled, x), stream(f, x), atol=1e-10)
+
+
+def test_iir_at_other_rate_passes_through():
+    b, a = butter(2, 40.0, fs=FS)
+    x = np.random.default_rng(4).standard_normal(500)
+    assert np.array_equal(stream(IirFilter(b=list(b), a=list(a), design_rate=FS), x, fs=500.0), x)
+
+
+def test_iir_rejects_bad_coefficients():
+    for kwargs in ({"b": [1.0], "a": [0.0, 1.0]}, {"b": [], "a": [1.0]},
+                   {"b": [1.0], "a": [1.0], "design_rate": 0.0}):
+        try:
+            IirFilter(**{"design_rate": FS, **kwargs})
+        except ConfigError:
+            continue
+        raise AssertionError(f"accepted {kwargs}")
+
+
+def test_moving_average_nulls_window_frequency():
+    f = MovingAverageFilter(window_ms=20.0)           # 20 samples at 1 kHz
+    assert gain(f, 50.0) < 1e-6                       # whole periods average out
+    assert gain(f, 100.0) < 1e-6
+    assert gain(f, 1.0) > 0.99
+
+
+def test_moving_average_passes_dc_without_startup_dip():
+    x = np.full(1000, 5.0)
+    assert np.allclose(stream(MovingAverageFilter(window_ms=20.0), x), 5.0, atol=1e-12)
+
+
+def test_moving_average_delay_is_half_window():
+    f = MovingAverageFilter(window_ms=20.0)
+    x = np.arange(3000, dtype=np.float64)             # ramp: output lags by the delay
+    y = stream(f, x, chunk=37)
+    assert f.group_delay(10.0, FS) == 9.5
+    assert np.allclose(y[19:], x[19:] - 9.5, atol=1e-9), y[19:25]
+    # Offline version is centred (an even window to within half a sample)
+    centred = f.filter_offline(x, FS)
+    assert np.allclose(centred[20:-20], x[20:-20] + 0.5, atol=1e-9), centred[20:25]
+
+
+def test_moving_average_streaming_matches_one_pass():
+    x = np.random.default_rng(5).standard_normal(5000)
+    f = MovingAverageFilter(window_ms=33.0)
+    assert np.allclose(stream(f, x, chunk=7), stream(f, x, chunk=x.shape[0]), atol=1e-10)
+
+
+def test_baseline_removal_takes_out_dc_offset():
+    t = np.arange(int(30 * FS)) / FS
+    wave = 100.0 * np.sin(2 * pi * 1.0 * t)
+    y = stream(BaselineRemovalFilter(cutoff_hz=0.1), 500.0 + wave)
+    tail = slice(-int(10 * FS), None)                 # τ = 1.6 s; 20 s settled
+    assert abs(np.mean(y[tail])) < 0.5, np.mean(y[tail])
+    assert np.sqrt(np.mean(y[tail] ** 2)) / np.sqrt(np.mean(wave[tail] ** 2)) > 0.99
+
+
+def test_baseline_removal_ramp_leaves_small_constant_offset():
+    # A one-pole high-pass turns a ramp into a constant: slope / (2π·cutoff)
+    f = BaselineRemovalFilter(cutoff_hz=0.1)
+    t = np.arange(int(30 * FS)) / FS
+    slope = 2.0                                       # µV/s of drift
+    wave = 100.0 * np.sin(2 * pi * 1.0 * t)
+    y = stream(f, slope * t + 5000.0 + wave)
+    (g, _, _, _, a1, _), = f.coefficients(FS)
+    offset = g * slope / FS / (1.0 + a1)              # steady state of the recursion
+    tail = slice(-int(10 * FS), None)
+    assert abs(offset - slope / (2 * pi * 0.1)) < 0.01 * offset
+    assert abs(np.mean(y[tail]) - offset) < 0.5, (np.mean(y[tail]), offset)
+    assert abs(np.mean(y[tail])) < 0.05 * 100.0       # oscillates around ~zero
+
+
+def test_baseline_removal_passband():
+    f = BaselineRemovalFilter(cutoff_hz=0.1)
+    assert gain(f, 1.0, duration_s=20.0) > 0.99       # 1/√(1 + (0.1/1)²) = 0.995
+    assert gain(f, 0.5, duration_s=20.0) > 0.97
+    assert gain(f, 200.0) > 0.999
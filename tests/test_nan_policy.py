@@ -0,0 +1,80 @@
+"""Behaviour check: nan_policy hold/zero/skip clean NaN and ±Inf before any module.
+
+Run:
+    pytest tests/test_nan_policy.py
+"""
+
+import numpy as np
+
+from dnb.core.types import PipelineConfig
+from dnb.engine.pipeline import Pipeline
+from dnb.modules import NotchFilter
+from dnb.sources import ArraySource
+
+FS = 100.0
+CHUNK_S = 0.05   # 5 samples
+# 1, 2, ..., 20 with NaN first (before any finite sample), a ±Inf pair,
+# a NaN opening a chunk, and one chunk that is entirely NaN
+SIGNAL = np.arange(1.0, 21.0)
+BAD = {0: np.nan, 7: np.inf, 8: -np.inf, 10: np.nan, 15: np.nan, 16: np.nan,
+       17: np.nan, 18: np.nan, 19: np.nan}
+
+
+def run(policy: str, modules=None):
+    """Returns (samples, timestamps) every module saw, and the pipeline.
+
+    Also checks that each result's raw_chunk is the source chunk, NaNs included.
+    """
+    x = SIGNAL.copy()
+    for i, v in BAD.items():
+        x[i] = v
+    pipeline = Pipeline(
+        source=ArraySource(x, FS),
+        modules=modules or [],
+        config=PipelineConfig(sample_rate=FS, chunk_duration=CHUNK_S, nan_policy=policy),
+    )
+    samples, timestamps, raw = [], [], []
+
+    def collect(result):
+        samples.append(result.chunk.samples)
+        timestamps.append(result.chunk.timestamps)
+        raw.append(result.raw_chunk.samples)
+
+    pipeline.run_offline(result_callback=collect)
+    assert np.array_equal(np.concatenate(raw), x, equal_nan=True), (policy, raw)
+    return np.concatenate(samples), np.concatenate(timestamps), pipeline
+
+
+def test_hold_repeats_last_finite_sample():
+    samples, timestamps, pipeline = run("hold")
+    expected = SIGNAL.copy()
+    expected[0] = 0.0                 # nothing to hold yet
+    expected[7] = expected[8] = 7.0
+    expected[10] = 10.0               # held across the chunk boundary
+    expected[15:] = 15.0
+    assert np.array_equal(samples, expected), samples
+    assert np.array_equal(timestamps, np.arange(20) / FS)
+    assert pipeline.nonfinite_samples == len(BAD), pipeline.nonfinite_samples
+
+
+def test_zero_replaces_with_zero():
+    samples, timestamps, pipeline = run("zero")
+    expected = SIGNAL.copy()
+    expected[list(BAD)] = 0.0
+    assert np.array_equal(samples, expected), samples
+    assert np.array_equal(timestamps, np.arange(20) / FS)
+    assert pipeline.nonfinite_samples == len(BAD), pipeline.nonfinite_samples
+
+
+def test_skip_drops_samples_and_timestamps():
+    samples, timestamps, pipeline = run("skip")
+    keep = [i for i in range(20) if i not in BAD]
+    assert np.array_equal(samples, SIGNAL[keep]), samples
+    assert np.array_equal(timestamps, np.array(keep) / FS), timestamps
+    assert pipeline.nonfinite_samples == len(BAD), pipeline.nonfinite_samples
+
+
+def test_filter_state_is_not_poisoned():
+    for policy in ("hold", "zero", "skip"):
+        samples, _, _ = run(policy, [NotchFilter(freq=10.0)])
+        assert np.all(np.isfinite(samples)), (policy, samples)
@@ -0,0 +1,67 @@
+"""Behaviour check: TWaveDetector's EMA period estimate converges.
+
+The wavelet output is synthesised directly (see test_data.wavelet_now),
+so the dominant frequency per chunk is known exactly.
+
+Run:
+    pytest tests/test_period_smoothing.py
+"""
+
+from dnb.modules import TWaveDetector
+from test_data import wavelet_now
+
+FS = 500.0
+CHUNK = 10
+FREQS = [0.5, 1.0, 2.0]
+
+
+def detector(period_smoothing: float | None) -> TWaveDetector:
+    # Only the prediction is under test: no validation gates, generous limit
+    return TWaveDetector(
+        freq_range=(0.5, 2.0), prediction_limit_s=10.0, amp_min=0.0, amp_max=1e6,
+        hilo_ratio_max=None, template_threshold=None, warmup_chunks=0,
+        period_smoothing=period_smoothing,
+    )
+
+
+def predicted_freqs(det: TWaveDetector, dominant: list[float]) -> list[float]:
+    """Candidate frequency per chunk, with `dominant` the strongest band per chunk."""
+    out = []
+    for i, f in enumerate(dominant):
+        amps = [100.0 if fr == f else 10.0 for fr in FREQS]
+        result = det.process(wavelet_now(FREQS, amps, [1.0] * len(FREQS), FS, i * CHUNK, CHUNK))
+        state = result.detections[det.id]
+        assert state["active"], f"chunk {i}: not active ({state.get('reject_reason')})"
+        out.append(state["candidates"][0]["frequency"])
+    return out
+
+
+def test_ema_converges_geometrically_after_step():
+    alpha = 0.2
+    freqs = predicted_freqs(detector(alpha), [1.0] * 5 + [2.0] * 30)
+    assert all(abs(f - 1.0) < 1e-12 for f in freqs[:5]), freqs[:5]
+    for k, f in enumerate(freqs[5:], start=1):
+        # period_k = 0.5 + (1.0 − 0.5)·(1 − α)^k
+        expected_period = 0.5 + 0.5 * (1 - alpha) ** k
+        assert abs(1.0 / f - expected_period) < 1e-9, (k, 1.0 / f, expected_period)
+    assert abs(freqs[-1] - 2.0) / 2.0 < 0.01, freqs[-1]
+
+
+def test_ema_damps_alternating_jitter():
+    freqs = predicted_freqs(detector(0.1), [1.0, 2.0] * 40)
+    periods = [1.0 / f for f in freqs[-10:]]
+    # Raw periods swing between 0.5 and 1.0 s; the smoothed one stays near 0.75 s
+    assert max(periods) - min(periods) < 0.06, periods
+    assert all(abs(p - 0.75) < 0.05 for p in periods), periods
+
+
+def test_no_smoothing_uses_instantaneous_frequency():
+    dominant = [1.0, 2.0, 0.5, 2.0]
+    assert predicted_freqs(detector(None), dominant) == dominant
+
+
+def test_reset_clears_ema():
+    det = detector(0.2)
+    predicted_freqs(det, [2.0] * 10)
+    det.reset()
+    assert predicted_freqs(det, [0.5]) == [0.5]
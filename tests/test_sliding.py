@@ -0,0 +1,89 @@
+"""Behaviour check: run_sliding reports each event once, and no event twice.
+
+A marker detector goes active on the chunk holding a marker sample and
+predicts a stim 0.2 s later; StimTrigger turns that into a 10 Hz train.
+Windows are 20 s with a 10 s hop, so a marker at 15 s is seen by two
+windows and must be reported once, with every pulse of its train.
+
+Run:
+    pytest tests/test_sliding.py
+"""
+
+import numpy as np
+
+from dnb.core.types import EventType, PipelineConfig
+from dnb.engine import run_sliding
+from dnb.modules import StimTrigger
+from dnb.modules.base import Module, ProcessResult
+
+FS = 100.0
+DURATION = 40.0
+N_PULSES = 5
+
+
+class MarkerDetector(Module):
+    """Active on chunks containing a sample > 0.5; stim predicted 0.2 s after it."""
+
+    id = "marker"
+
+    def configure(self, config: PipelineConfig) -> None:
+        pass
+
+    def process(self, result: ProcessResult) -> ProcessResult:
+        hits = np.flatnonzero(result.chunk.samples > 0.5)
+        candidates = [{
+            "timestamp": float(result.chunk.timestamps[i]) + 0.2, "frequency": 1.0,
+            "amplitude": 1.0, "phase_now": 0.0,
+        } for i in hits[:1]]
+        result.detections[self.id] = {"active": bool(candidates), "candidates": candidates}
+        return result
+
+
+def make_modules() -> list[Module]:
+    return [
+        MarkerDetector(),
+        StimTrigger(activation_detector_id="marker", inhibition_detector_id=None,
+                    n_pulses=N_PULSES, inter_pulse_interval_s=0.1, backoff_s=1.0),
+    ]
+
+
+def run(markers_s: list[float]):
+    signal = np.zeros(int(DURATION * FS))
+    for t in markers_s:
+        signal[int(t * FS)] = 1.0
+    events = run_sliding(signal, FS, make_modules, window_s=20.0, hop_s=10.0,
+                         config=PipelineConfig(sample_rate=FS, chunk_duration=0.1))
+    stims = [e for e in events if e.event_type == EventType.STIM]
+    detections = [e for e in events if e.event_type == EventType.SLOW_WAVE]
+    return detections, stims
+
+
+def test_every_pulse_of_a_10hz_train_survives():
+    detections, stims = run([5.0])
+    assert len(detections) == 1, detections
+    assert [e.metadata["pulse_index"] for e in stims] == list(range(1, N_PULSES + 1)), stims
+    t0 = stims[0].timestamp
+    for k, e in enumerate(stims):
+        assert abs(e.timestamp - (t0 + 0.1 * k)) < 1e-9, (k, e.timestamp)
+
+
+def test_overlap_event_reported_once():
+    # 15 s is in windows [0, 20) and [10, 30); 25 s in [10, 30) and [20, 40)
+    detections, stims = run([5.0, 15.0, 25.0, 35.0])
+    assert len(detections) == 4, [e.timestamp for e in detections]
+    assert len(stims) == 4 * N_PULSES, [e.timestamp for e in stims]
+    for d in detections:
+        train = [e for e in stims if 0.0 < e.timestamp - d.timestamp < 1.0]
+        assert [e.metadata["pulse_index"] for e in train] == list(range(1, N_PULSES + 1)), train
+
+
+def test_close_detections_are_not_merged():
+    # 1.1 s apart, inside a tolerance of 2 s, and both in an overlap
+    signal = np.zeros(int(DURATION * FS))
+    for t in (15.0, 16.1):
+        signal[int(t * FS)] = 1.0
+    events = run_sliding(signal, FS, make_modules, window_s=20.0, hop_s=10.0,
+                         config=PipelineConfig(sample_rate=FS, chunk_duration=0.1),
+                         dedup_tolerance_s=2.0)
+    detections = [e for e in events if e.event_type == EventType.SLOW_WAVE]
+    assert len(detections) == 2, [e.timestamp for e in detections]
@@ -0,0 +1,156 @@
+"""Behaviour check: StimTrigger turns detector candidates into STIM events.
+
+The trigger is fed hand-written detections, so stim times can be checked
+exactly against the candidate's predicted timestamp.
+
+Run:
+    pytest tests/test_stim_trigger.py
+"""
+
+import numpy as np
+
+from dnb.core.types import EventType
+from dnb.errors import ConfigError
+from dnb.modules import StimTrigger
+from dnb.modules.base import ProcessResult
+from test_data import chunk_at
+
+FS = 500.0
+CHUNK = 10
+
+
+def chunk_result(index: int, detections: dict) -> ProcessResult:
+    """The index-th chunk, carrying the given detections."""
+    chunk = chunk_at(np.zeros(CHUNK), FS, index * CHUNK)
+    return ProcessResult(chunk=chunk, detections=detections)
+
+
+def candidate(t_now: float, dt: float, freq: float = 1.0) -> dict:
+    return {
+        "timestamp": t_now + dt, "frequency": freq, "amplitude": 100.0,
+        "phase_now": 0.0, "dt_to_target_ms": dt * 1000,
+    }
+
+
+def fire(trigger: StimTrigger, dt: float = 0.3, freq: float = 1.0, index: int = 10):
+    """One active slow_wave chunk; returns (t_now, SLOW_WAVE events, STIM events)."""
+    result = chunk_result(index, {})
+    t_now = float(result.chunk.timestamps[-1])
+    result.detections["slow_wave"] = {"active": True, "candidates": [candidate(t_now, dt, freq)]}
+    events = trigger.process(result).events
+    return (
+        t_now,
+        [e for e in events if e.event_type == EventType.SLOW_WAVE],
+        [e for e in events if e.event_type == EventType.STIM],
+    )
+
+
+def test_fixed_rate_train_spacing():
+    trigger = StimTrigger(inhibition_detector_id=None, n_pulses=4, inter_pulse_interval_s=0.25)
+    t_now, _, stims = fire(trigger, dt=0.3, freq=1.0)
+    assert [e.metadata["pulse_index"] for e in stims] == [1, 2, 3, 4], stims
+    for k, e in enumerate(stims):
+        assert abs(e.timestamp - (t_now + 0.3 + k * 0.25)) < 1e-12, (k, e.timestamp)
+
+
+def test_default_spacing_is_one_wave_period():
+    trigger = StimTrigger(inhibition_detector_id=None, n_pulses=3)
+    t_now, _, stims = fire(trigger, dt=0.2, freq=2.0)
+    assert len(stims) == 3, stims
+    for k, e in enumerate(stims):
+        assert abs(e.timestamp - (t_now + 0.2 + k * 0.5)) < 1e-12, (k, e.timestamp)
+
+
+def test_non_positive_interval_is_rejected():
+    for bad in (0.0, -0.1):
+        try:
+            StimTrigger(inter_pulse_interval_s=bad)
+        except ConfigError:
+            continue
+        raise AssertionError(f"inter_pulse_interval_s={bad} accepted")
+
+
+def test_stim_delay_shifts_stim_exactly():
+    base_now, _, base = fire(StimTrigger(inhibition_detector_id=None, n_pulses=2))
+    for delay in (0.05, -0.1):
+        trigger = StimTrigger(inhibition_detector_id=None, n_pulses=2, stim_delay_s=delay)
+        t_now, (sw,), stims = fire(trigger)
+        assert t_now == base_now
+        shifts = [s.timestamp - b.timestamp for s, b in zip(stims, base)]
+        assert all(abs(d - delay) < 1e-12 for d in shifts), (delay, shifts)
+        assert abs(sw.metadata["dt_to_stim_ms"] - (300.0 + delay * 1000)) < 1e-9, sw.metadata
+        assert sw.metadata["stim_clamped"] is False
+        assert all(s.metadata["stim_clamped"] is False for s in stims)
+
+
+def test_negative_delay_past_detection_is_clamped_and_flagged():
+    trigger = StimTrigger(inhibition_detector_id=None, n_pulses=1, stim_delay_s=-0.5)
+    t_now, (sw,), (stim,) = fire(trigger, dt=0.3)
+    assert stim.timestamp == t_now, (stim.timestamp, t_now)
+    assert stim.metadata["stim_clamped"] is True
+    assert sw.metadata["stim_clamped"] is True and sw.metadata["dt_to_stim_ms"] == 0.0, sw.metadata
+
+
+def fire_combined(trigger: StimTrigger, states: dict[str, tuple[bool, float | None]]):
+    """One chunk with detector id -> (active, candidate dt or None); returns STIM events."""
+    result = chunk_result(10, {})
+    t_now = float(result.chunk.timestamps[-1])
+    for det_id, (active, dt) in states.items():
+        result.detections[det_id] = {
+            "active": active, "candidates": [candidate(t_now, dt)] if dt is not None else [],
+        }
+    stims = [e for e in trigger.process(result).events if e.event_type == EventType.STIM]
+    return t_now, stims
+
+
+def combined(mode: str, **kwargs) -> StimTrigger:
+    return StimTrigger(
+        activation_detector_id=["slow_wave", "spindle"], activation_mode=mode,
+        inhibition_detector_id=None, **kwargs,
+    )
+
+
+def test_all_mode_needs_every_detector():
+    for slow, spindle, fires in [(True, True, True), (True, False, False),
+                                 (False, True, False), (False, False, False)]:
+        _, stims = fire_combined(combined("all"), {
+            "slow_wave": (slow, 0.3 if slow else None), "spindle": (spindle, None),
+        })
+        assert len(stims) == int(fires), (slow, spindle, stims)
+    assert combined("all").activation_id == "slow_wave&spindle"
+
+
+def test_any_mode_needs_one_detector():
+    for slow, spindle, fires in [(True, True, True), (True, False, True),
+                                 (False, False, False)]:
+        _, stims = fire_combined(combined("any"), {
+            "slow_wave": (slow, 0.3 if slow else None), "spindle": (spindle, None),
+        })
+        assert len(stims) == int(fires), (slow, spindle, stims)
+    # Active but without phase-predicted candidates: nothing to time a stim by
+    _, stims = fire_combined(combined("any"), {
+        "slow_wave": (False, None), "spindle": (True, None),
+    })
+    assert stims == [], stims
+    assert combined("any").activation_id == "slow_wave|spindle"
+
+
+def test_timing_from_first_listed_or_timing_detector():
+    states = {"slow_wave": (True, 0.3), "spindle": (True, 0.2)}
+    t_now, (stim,) = fire_combined(combined("all"), states)
+    assert abs(stim.timestamp - (t_now + 0.3)) < 1e-12, "list order: slow_wave first"
+    t_now, (stim,) = fire_combined(combined("all", timing_detector_id="spindle"), states)
+    assert abs(stim.timestamp - (t_now + 0.2)) < 1e-12, "timing_detector_id: spindle"
+    # any: the timing detector itself must be active
+    _, stims = fire_combined(combined("any", timing_detector_id="spindle"), {
+        "slow_wave": (True, 0.3), "spindle": (False, None),
+    })
+    assert stims == [], stims
+
+
+def test_timing_detector_must_be_listed():
+    try:
+        combined("all", timing_detector_id="ripple")
+    except ConfigError:
+        return
+    raise AssertionError("timing_detector_id outside the activation list accepted")
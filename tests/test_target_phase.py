@@ -0,0 +1,78 @@
+"""Behaviour check: target_phase_deg (sine convention) lands on that phase of a sine.
+
+The detector is fed the analytic phase of a synthetic sine (Hilbert
+transform) at several points of a cycle; the predicted target time must
+fall where the sine is at the configured phase.
+
+Run:
+    pytest tests/test_target_phase.py
+"""
+
+from math import pi
+
+import numpy as np
+from scipy.signal import hilbert
+
+from dnb.config import build_modules, validate_config
+from dnb.modules import TWaveDetector
+from test_data import wavelet_now
+
+FS = 500.0
+FREQ = 1.0
+CHUNK = 10
+
+
+def detector_for(target_wave: dict) -> TWaveDetector:
+    cfg = {"target_wave": {"prediction_limit_s": 1.1, "warmup_chunks": 0, **target_wave}}
+    return next(m for m in build_modules(cfg) if isinstance(m, TWaveDetector))
+
+
+def predicted_sine_phases(det: TWaveDetector) -> list[float]:
+    """Sine phase (rad, ω·t) at the predicted target, from 8 points across one cycle."""
+    t = np.arange(int(10 * FS)) / FS
+    analytic = hilbert(100.0 * np.sin(2 * pi * FREQ * t))
+    phases = []
+    for k in range(8):
+        i = int((5.0 + k / 8) * FS)  # last sample of the chunk = "now"
+        det.reset()
+        result = det.process(wavelet_now(
+            [FREQ], [100.0], [float(np.angle(analytic[i]))], FS, i - CHUNK + 1, CHUNK,
+        ))
+        state = result.detections[det.id]
+        assert state["active"], f"point {k}: {state.get('reject_reason')}"
+        t_target = state["candidates"][0]["timestamp"]
+        assert t[i] < t_target <= t[i] + 1.0 / FREQ + 1e-9, (t[i], t_target)
+        phases.append(2 * pi * FREQ * t_target)
+    return phases
+
+
+def assert_lands_on(det: TWaveDetector, sine_deg: float) -> None:
+    want = np.radians(sine_deg)
+    for phase in predicted_sine_phases(det):
+        err = (phase - want + pi) % (2 * pi) - pi
+        assert abs(err) < 0.01, f"{sine_deg}°: landed at {np.degrees(phase) % 360:.1f}°"
+
+
+def test_zero_deg_is_rising_zero_crossing():
+    assert_lands_on(detector_for({"target_phase_deg": 0}), 0.0)
+
+
+def test_ninety_deg_is_up_state_peak():
+    assert_lands_on(detector_for({"target_phase_deg": 90}), 90.0)
+
+
+def test_two_seventy_deg_is_trough():
+    assert_lands_on(detector_for({"target_phase_deg": 270}), 270.0)
+
+
+def test_ninety_deg_equals_zero_radians():
+    # target_phase keeps the package's cosine map: 0 rad = peak
+    assert_lands_on(detector_for({"target_phase": 0.0}), 90.0)
+    assert_lands_on(detector_for({"target_phase": "pi"}), 270.0)
+
+
+def test_degree_suffix_on_radian_key_is_rejected():
+    base = {"source": {"type": "file", "path": "x.npz"}}
+    assert validate_config({**base, "target_wave": {"target_phase": "3pi/2"}}) == []
+    problems = validate_config({**base, "target_wave": {"target_phase": "90deg"}})
+    assert len(problems) == 1 and "target_phase_deg" in problems[0], problems
@@ -0,0 +1,84 @@
+"""Behaviour check: template_metric scores a clean sinusoid above a distorted wave.
+
+The ring buffer holds 2 s (two periods) of a 1 Hz wave in phase with
+the wavelet's estimate; scores are read from the candidate log. For a
+unit-amplitude square wave the fundamental is 4/π, which gives the
+reference values below.
+
+Run:
+    pytest tests/test_template_metrics.py
+"""
+
+from math import pi, sqrt
+
+import numpy as np
+
+from dnb.core.ring_buffer import RingBuffer
+from dnb.modules import TWaveDetector
+from test_data import wavelet_now
+
+FS = 500.0
+CHUNK = 10
+FREQS = [0.5, 1.0, 2.0]
+PHASE = 1.0
+AMP = 100.0
+
+
+def wave_buffer(shape, amplitude: float = AMP) -> RingBuffer:
+    """2 s of amplitude·shape(phase) at 1 Hz, phased like test_data.cosine_buffer."""
+    n = int(2.0 * FS)
+    t = (np.arange(n) - n) / FS
+    buf = RingBuffer(n)
+    buf.write(amplitude * shape(2 * pi * t + PHASE))
+    return buf
+
+
+def score(metric: str, buf: RingBuffer, wavelet_amp: float = AMP, **kwargs) -> dict:
+    """The logged candidate for one chunk: template_score, passed, reject_reason."""
+    det = TWaveDetector(
+        freq_range=(0.5, 2.0), prediction_limit_s=1.0, amp_min=10.0, amp_max=1000.0,
+        hilo_ratio_max=None, warmup_chunks=0, record_candidates=True,
+        template_metric=metric, **{"template_threshold": None, **kwargs},
+    )
+    result = wavelet_now(FREQS, [1.0, wavelet_amp, 1.0], [PHASE] * 3, FS, 0, CHUNK)
+    result.ring_buffer = buf
+    det.process(result)
+    return det.candidate_log[0]
+
+
+def square(phase):
+    return np.sign(np.cos(phase))
+
+
+def test_cosine_metric():
+    assert abs(score("cosine", wave_buffer(np.cos))["template_score"] - 1.0) < 1e-6
+    s = score("cosine", wave_buffer(square))["template_score"]
+    assert abs(s - (4 / pi) / sqrt(2)) < 0.01, s                     # 0.900
+    # Amplitude plays no part
+    assert abs(score("cosine", wave_buffer(np.cos, 5.0), 5.0)["template_score"] - 1.0) < 1e-6
+
+
+def test_dot_metric():
+    # z-scored sinusoid · unit cosine averages to 1/√2 over whole periods
+    assert abs(score("dot", wave_buffer(np.cos))["template_score"] - 1 / sqrt(2)) < 1e-3
+    s = score("dot", wave_buffer(square))["template_score"]
+    assert abs(s - 2 / pi) < 0.01, s                                 # 0.637
+
+
+def test_nrmse_metric():
+    assert abs(score("nrmse", wave_buffer(np.cos))["template_score"] - 1.0) < 1e-6
+    # Square wave against its fundamental: residual = the harmonics
+    fundamental = 4 / pi * AMP
+    s = score("nrmse", wave_buffer(square), fundamental)["template_score"]
+    assert abs(s - (1 - sqrt(1 - 8 / pi ** 2) / (4 / pi / sqrt(2)))) < 0.01, s   # 0.517
+    # Scale matters: the wavelet reporting twice the amplitude halves the score
+    s = score("nrmse", wave_buffer(np.cos), 2 * AMP)["template_score"]
+    assert abs(s - 0.5) < 1e-6, s
+
+
+def test_threshold_separates_sine_from_square():
+    for metric, threshold in (("dot", 0.68), ("cosine", 0.95), ("nrmse", 0.8)):
+        sine = score(metric, wave_buffer(np.cos), template_threshold=threshold)
+        sq = score(metric, wave_buffer(square), 4 / pi * AMP, template_threshold=threshold)
+        assert sine["passed"] is True, (metric, sine)
+        assert sq["passed"] is False and sq["reject_reason"] == "template", (metric, sq)
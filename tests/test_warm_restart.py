@@ -0,0 +1,138 @@
+"""Behaviour check: save_state/load_state resume a run exactly where it stopped.
+
+A pipeline is saved mid-stream; a fresh pipeline built from the same
+config loads the file and processes the rest of the signal. Its
+detections must equal the uninterrupted run's, chunk for chunk.
+
+Run:
+    pytest tests/test_warm_restart.py
+"""
+
+import json
+import tempfile
+from math import pi
+from pathlib import Path
+
+import numpy as np
+
+from dnb.config import build_modules, build_pipeline_config
+from dnb.engine.pipeline import STATE_VERSION, Pipeline
+from dnb.errors import DnbIOError, ValidationError
+from dnb.sources import ArraySource
+
+FS = 500.0
+CFG = {
+    "pipeline": {"sample_rate": FS, "chunk_duration": 0.1, "buffer_duration": 10.0},
+    "filters": [{"type": "notch", "freq": 50.0}],
+    "target_wave": {"warmup_chunks": 20},
+    "amplitude_monitor": {"warmup_chunks": 20},
+    "envelope_detector": {"freq_range": [12.0, 16.0]},
+    "trigger": {"enabled": False},
+}
+SAVE_AFTER = 250   # chunks, i.e. 25 s
+
+
+def signal(duration_s: float = 40.0, seed: int = 0) -> np.ndarray:
+    rng = np.random.default_rng(seed)
+    t = np.arange(int(duration_s * FS)) / FS
+    so = 100.0 * np.sin(2 * pi * 0.8 * t) * (np.sin(2 * pi * 0.05 * t) > 0)
+    return so + 20.0 * np.sin(2 * pi * 50.0 * t) + 10.0 * rng.standard_normal(t.shape[0])
+
+
+def pipeline(samples: np.ndarray, t0: float = 0.0, cfg: dict = CFG) -> Pipeline:
+    return Pipeline(
+        source=ArraySource(samples, FS, t0=t0),
+        modules=build_modules(cfg),
+        config=build_pipeline_config(cfg),
+    )
+
+
+def detections_per_chunk(p: Pipeline, on_chunk=None) -> list[dict]:
+    out = []
+
+    def collect(result):
+        out.append(result.detections)
+        if on_chunk is not None:
+            on_chunk(len(out))
+
+    p.run_offline(result_callback=collect)
+    return out
+
+
+def same(a, b) -> bool:
+    if isinstance(a, dict):
+        return isinstance(b, dict) and a.keys() == b.keys() and all(same(a[k], b[k]) for k in a)
+    if isinstance(a, (list, tuple)):
+        return len(a) == len(b) and all(same(x, y) for x, y in zip(a, b))
+    if isinstance(a, np.ndarray):
+        return np.array_equal(a, b)
+    return a == b
+
+
+def test_resumed_run_matches_uninterrupted_run():
+    x = signal()
+    n = int(0.1 * FS)
+    with tempfile.TemporaryDirectory() as d:
+        path = Path(d) / "state.json"
+        first = pipeline(x)
+
+        def save(k: int) -> None:
+            if k == SAVE_AFTER:
+                first.save_state(path)
+
+        full = detections_per_chunk(first, save)
+        assert json.loads(path.read_text())["version"] == STATE_VERSION
+
+        second = pipeline(x[SAVE_AFTER * n:], t0=SAVE_AFTER * n / FS)
+        second.load_state(path)
+        resumed = detections_per_chunk(second)
+
+    assert len(resumed) == len(full) - SAVE_AFTER
+    assert any(det["slow_wave"]["active"] for det in full[SAVE_AFTER:]), "no detections to compare"
+    for i, (a, b) in enumerate(zip(full[SAVE_AFTER:], resumed)):
+        assert same(a, b), f"chunk {SAVE_AFTER + i}: {a} != {b}"
+
+
+def test_cold_start_differs():
+    # Sanity check of the test itself: without the state, warmup starts over
+    x = signal()
+    n = int(0.1 * FS)
+    full = detections_per_chunk(pipeline(x))
+    cold = detections_per_chunk(pipeline(x[SAVE_AFTER * n:], t0=SAVE_AFTER * n / FS))
+    assert not same(full[SAVE_AFTER], cold[0])
+
+
+def test_mismatched_modules_are_rejected():
+    x = signal(5.0)
+    with tempfile.TemporaryDirectory() as d:
+        path = Path(d) / "state.json"
+        p = pipeline(x)
+        p.run_offline()
+        p.save_state(path)
+
+        renamed = {**CFG, "envelope_detector": {**CFG["envelope_detector"], "id": "sigma"}}
+        for other in (renamed, {**CFG, "filters": []}):
+            try:
+                pipeline(x, cfg=other).load_state(path)
+            except ValidationError as e:
+                assert "different modules" in str(e), e
+                continue
+            raise AssertionError(f"state loaded into {other}")
+
+        state = json.loads(path.read_text())
+        state["version"] = STATE_VERSION - 1
+        path.write_text(json.dumps(state))
+        try:
+            pipeline(x).load_state(path)
+        except ValidationError as e:
+            assert "version" in str(e), e
+        else:
+            raise AssertionError("old format version accepted")
+
+        path.write_bytes(b"\x80\x04not json")
+        try:
+            pipeline(x).load_state(path)
+        except DnbIOError:
+            pass
+        else:
+            raise AssertionError("non-JSON file accepted")